@@ -0,0 +1,58 @@
+//! Benchmarks parsing and each part of every day, on the real puzzle input when it is present and
+//! on every example fixture.
+//!
+//! Benchmarks are named `dayNN/<input|example name>/<parse|part1|part2>`, so one day or one step
+//! can be picked out with a filter, e.g. `cargo bench --bench days -- day07/input`. Save a baseline
+//! before a change with `--save-baseline before` and compare against it with `--baseline before`.
+
+use adv_code_2024::days;
+use adv_code_2024::examples::{self, EXAMPLES_DIR};
+use adv_code_2024::input::InputSource;
+use adv_code_2024::Solver;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Duration;
+
+fn bench_input(c: &mut Criterion, solver: &dyn Solver, name: &str, input: &str, parts: &[u8]) {
+    let mut group = c.benchmark_group(format!("day{:02}/{}", solver.day(), name));
+
+    group.bench_function("parse", |b| b.iter(|| solver.parse_any(&mut black_box(input.as_bytes())).unwrap()));
+
+    let parsed = solver.parse_any(&mut input.as_bytes()).unwrap();
+    for &part in parts {
+        group.bench_function(format!("part{}", part), |b| {
+            b.iter(|| solver.part_any(black_box(parsed.as_ref()), part).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+
+    for solver in days::DAYS {
+        if let Ok(input) = InputSource::resolve(solver.day(), None).and_then(|source| source.read()) {
+            let parts: Vec<u8> = (1..=solver.parts()).collect();
+            bench_input(c, solver, "input", &input, &parts);
+        }
+
+        for name in examples::names(&examples_dir, solver.day()).unwrap() {
+            let example = examples::load(&examples_dir, solver.day(), &name).unwrap();
+            let configured = days::configure(solver.day(), &example.params).unwrap();
+            let solver = configured.as_deref().unwrap_or(solver);
+            // Only the parts the example has answers for: some examples are meant for one part.
+            let parts: Vec<u8> = (1..=2).filter(|&part| example.expected.part(part).is_some()).collect();
+            bench_input(c, solver, &name, &example.input, &parts);
+        }
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // Several parts take a good fraction of a second, so keep the sample count low.
+    config = Criterion::default().sample_size(10).warm_up_time(Duration::from_millis(500));
+    targets = bench_days
+}
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Generates one test per example fixture in `examples/NN/*.txt`, included by `tests/examples.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+
+    let mut fixtures = Vec::new();
+    if let Ok(days) = fs::read_dir("examples") {
+        for day in days.flatten() {
+            let Some(number) = day.file_name().to_str().and_then(|name| name.parse::<u8>().ok()) else {
+                continue;
+            };
+
+            for file in fs::read_dir(day.path()).into_iter().flatten().flatten() {
+                let path = file.path();
+                if path.extension().is_some_and(|ext| ext == "txt") {
+                    if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                        fixtures.push((number, name.to_string()));
+                    }
+                }
+            }
+        }
+    }
+    fixtures.sort();
+
+    let mut tests = String::new();
+    for (day, name) in fixtures {
+        let ident: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+        writeln!(tests, "#[test]\nfn day{:02}_{}() {{\n    check({}, {:?});\n}}\n", day, ident, day, name).unwrap();
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to one puzzle part.
+///
+/// Most parts produce a number; a few produce text such as a comma-separated
+/// program output or a password. Numbers too large for `i64` are kept as text
+/// so no answer is ever truncated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    /// Reads a number when the text is one, and keeps it as text otherwise.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(s.parse().map(Answer::Int).unwrap_or_else(|_| Answer::Text(s.to_string())))
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                i64::try_from(value).map(Answer::Int).unwrap_or_else(|_| Answer::Text(value.to_string()))
+            }
+        }
+
+        impl PartialEq<$t> for Answer {
+            fn eq(&self, other: &$t) -> bool {
+                match (self, i64::try_from(*other)) {
+                    (Answer::Int(value), Ok(other)) => *value == other,
+                    (Answer::Text(text), Err(_)) => text.parse::<$t>().is_ok_and(|value| value == *other),
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq<Answer> for $t {
+            fn eq(&self, other: &Answer) -> bool {
+                other == self
+            }
+        }
+    )*};
+}
+
+from_int!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(text) if text == other)
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_with_plain_values() {
+        assert_eq!(11, Answer::from(11usize));
+        assert_eq!("6,1", Answer::from(format!("{},{}", 6, 1)));
+        assert_ne!(Answer::Int(11), Answer::Text("11".to_string()));
+    }
+
+    #[test]
+    fn parses_numbers_and_text() {
+        assert_eq!(Answer::Int(-3), "-3".parse::<Answer>().unwrap());
+        assert_eq!(Answer::from("co,de,ka,ta"), "co,de,ka,ta".parse::<Answer>().unwrap());
+        assert_eq!(Answer::Text(u64::MAX.to_string()), Answer::from(u64::MAX));
+    }
+}
@@ -0,0 +1,117 @@
+//! Known puzzle answers, kept in `answers/NN.toml` so refactors can be checked against them.
+//!
+//! Each file holds the accepted answer of every solved part:
+//!
+//! ```toml
+//! part1 = 3246517
+//! part2 = 29379307
+//! ```
+
+use crate::Answer;
+use anyhow::*;
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const ANSWERS_DIR: &str = "answers";
+
+/// Expected answers for one day; parts without a known answer are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// The answers directory in the crate root, so `verify` checks the same files from any directory.
+pub fn answers_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_DIR)
+}
+
+pub fn answers_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{:02}.toml", day))
+}
+
+/// Loads the expected answers of `day` from `dir`, or `None` when the day has no answers file yet.
+pub fn load(dir: &Path, day: u8) -> Result<Option<Expected>> {
+    let path = answers_path(dir, day);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let text = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+    toml::from_str(&text).map(Some).with_context(|| format!("invalid answers file {}", path.display()))
+}
+
+/// Outcome of checking one part against its expected answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&Answer>, actual: &Answer) -> Verdict {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.clone() },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numeric_and_text_answers() {
+        let expected: Expected = toml::from_str("part1 = 22\npart2 = \"6,1\"\n").unwrap();
+
+        assert_eq!(Some(&Answer::Int(22)), expected.part(1));
+        assert_eq!(Some(&Answer::from("6,1")), expected.part(2));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let expected = Expected { part1: Some(Answer::Int(11)), part2: None };
+
+        assert_eq!(Verdict::Pass, Verdict::check(expected.part(1), &Answer::Int(11)));
+        assert_eq!(
+            Verdict::Fail { expected: Answer::Int(11) },
+            Verdict::check(expected.part(1), &Answer::Int(12))
+        );
+        assert_eq!(Verdict::Missing, Verdict::check(expected.part(2), &Answer::Int(31)));
+    }
+
+    #[test]
+    fn loads_from_the_crate_root() -> Result<()> {
+        let expected = load(&answers_dir(), 1)?.context("day 1 has an answers file")?;
+
+        assert!(expected.part1.is_some());
+        assert_eq!(None, load(&answers_dir(), 0)?);
+        Ok(())
+    }
+}
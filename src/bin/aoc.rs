@@ -0,0 +1,260 @@
+use anyhow::*;
+use std::path::Path;
+use std::time::Duration;
+use adv_code_2024::answers::{self, Verdict};
+use adv_code_2024::cache::{Cached, DirFetch, Fetch, HttpFetch, InputCache, YEAR};
+use adv_code_2024::days;
+use adv_code_2024::input::{self, InputSource};
+use adv_code_2024::scaffold;
+use adv_code_2024::{Solver, Timed};
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->]
+    aoc verify [day|all] [--part <1|2>]
+    aoc fetch [day|all] [--from <dir>]
+    aoc new <day>
+
+Inputs are read from input/NN.txt, or from $AOC_INPUT_DIR/NN.txt when it is set.
+--input (a single day only) reads another file, or stdin with -.
+fetch fills the input cache (input/2024/NN.txt) from adventofcode.com using the session
+cookie in $AOC_SESSION, or from a directory laid out the same way with --from.
+";
+
+struct Row {
+    day: u8,
+    part: u8,
+    answer: String,
+    /// Time spent parsing the day's input, on the first row of each day only.
+    parse: Option<Duration>,
+    solve: Duration,
+}
+
+/// Days and part picked on the command line; no part means every part.
+struct Selection {
+    days: Vec<&'static dyn Solver>,
+    part: Option<u8>,
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("new") => new(&args[1..]),
+        _ => {
+            eprint!("{}", USAGE);
+            bail!("expected a command");
+        }
+    }
+}
+
+/// Parses `<day|all> [--part <1|2>]`; `default` is used when no day is given.
+fn parse_selection(args: &[String], default: Option<Vec<&'static dyn Solver>>) -> Result<Selection> {
+    let mut selected = default;
+    let mut part = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().context("--part needs a value")?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => bail!("invalid part '{}', expected 1 or 2", value),
+                };
+            }
+            "all" => selected = Some(days::DAYS.to_vec()),
+            day => {
+                let number: u8 = day.parse().with_context(|| format!("invalid day '{}'", day))?;
+                let day = days::get(number).with_context(|| format!("day {} is not solved", number))?;
+                selected = Some(vec![day]);
+            }
+        }
+    }
+
+    let days = selected.context("expected a day number or 'all'")?;
+    Ok(Selection { days, part })
+}
+
+impl Selection {
+    /// The selected parts of `solver`, in order.
+    fn parts(&self, solver: &dyn Solver) -> Vec<u8> {
+        (1..=solver.parts()).filter(|&p| self.part.is_none_or(|part| part == p)).collect()
+    }
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let mut args = args.to_vec();
+    let input = input::take_input_arg(&mut args)?;
+    let selection = parse_selection(&args, None)?;
+    ensure!(input.is_none() || selection.days.len() == 1, "--input needs a single day");
+
+    // Stdin can only be read once, so the text is shared by both parts.
+    let text = match &input {
+        Some(path) => Some(InputSource::resolve(selection.days[0].day(), Some(path))?.read()?),
+        None => None,
+    };
+
+    let mut rows = Vec::new();
+    for &solver in &selection.days {
+        let parts = selection.parts(solver);
+        let solved = match &text {
+            Some(text) => solve_text(solver, &parts, text),
+            None => solve(solver, &parts),
+        };
+
+        for (p, timed) in parts.into_iter().zip(solved) {
+            let (answer, parse, solve) = match timed {
+                Result::Ok(timed) => (timed.answer.to_string(), timed.parse, timed.solve),
+                Err(err) => (format!("error: {:#}", err), Duration::ZERO, Duration::ZERO),
+            };
+            let parse = rows.last().is_none_or(|row: &Row| row.day != solver.day()).then_some(parse);
+            rows.push(Row { day: solver.day(), part: p, answer, parse, solve });
+        }
+    }
+
+    print_table(&rows);
+    Ok(())
+}
+
+/// Checks every selected part against `answers/NN.toml` and fails if any part is wrong, errors, or
+/// is missing from a day's answers file.
+fn verify(args: &[String]) -> Result<()> {
+    let selection = parse_selection(args, Some(days::DAYS.to_vec()))?;
+
+    let mut failed = 0;
+    println!("{:<4} {:<4} {:<20} Status", "Day", "Part", "Answer");
+    for &solver in &selection.days {
+        let expected = answers::load(&answers::answers_dir(), solver.day())?;
+        let parts = selection.parts(solver);
+
+        for (p, timed) in parts.iter().zip(solve(solver, &parts)) {
+            let (answer, status) = match timed {
+                Result::Ok(timed) => {
+                    let verdict = Verdict::check(expected.as_ref().and_then(|e| e.part(*p)), &timed.answer);
+                    // Once a day has an answers file, every part of it needs an answer there
+                    let unrecorded = verdict == Verdict::Missing && expected.is_some();
+                    if matches!(verdict, Verdict::Fail { .. }) || unrecorded {
+                        failed += 1;
+                    }
+                    (timed.answer.to_string(), verdict.to_string())
+                }
+                Err(err) => {
+                    failed += 1;
+                    (String::new(), format!("error: {:#}", err))
+                }
+            };
+
+            println!("{:<4} {:<4} {:<20} {}", format!("{:02}", solver.day()), p, answer, status);
+        }
+    }
+
+    ensure!(failed == 0, "{} part(s) failed verification", failed);
+    Ok(())
+}
+
+/// Fills the input cache for every selected day, skipping days that are already cached.
+fn fetch(args: &[String]) -> Result<()> {
+    let mut args = args.to_vec();
+    let from = match args.iter().position(|arg| arg == "--from") {
+        Some(index) => {
+            ensure!(index + 1 < args.len(), "--from needs a directory");
+            let dir = args.remove(index + 1);
+            args.remove(index);
+            Some(dir)
+        }
+        None => None,
+    };
+    let selection = parse_selection(&args, Some(days::DAYS.to_vec()))?;
+
+    let backend: Box<dyn Fetch> = match from {
+        Some(dir) => Box::new(DirFetch::new(dir)),
+        None => Box::new(HttpFetch::from_env()?),
+    };
+    let cache = InputCache::new(input::cache_dir());
+
+    let mut failed = 0;
+    for solver in &selection.days {
+        let status = match cache.get(YEAR, solver.day(), backend.as_ref()) {
+            Result::Ok((_, Cached::Hit)) => "cached".to_string(),
+            Result::Ok((_, Cached::Fetched)) => "fetched".to_string(),
+            Err(err) => {
+                failed += 1;
+                format!("error: {:#}", err)
+            }
+        };
+        println!("{:02} {}", solver.day(), status);
+    }
+
+    ensure!(failed == 0, "{} input(s) could not be fetched", failed);
+    Ok(())
+}
+
+/// Creates and registers the module, binary and example fixture of a new day from the templates in
+/// `templates/`.
+fn new(args: &[String]) -> Result<()> {
+    let [day] = args else { bail!("expected a day number") };
+    let day: u8 = day.parse().with_context(|| format!("invalid day '{}'", day))?;
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for path in scaffold::create(root, day)? {
+        println!("created {}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    println!("registered day{:02} in {}", day, scaffold::REGISTRY);
+    println!("run it with: cargo run --bin {:02}", day);
+    Ok(())
+}
+
+/// Solves `parts` on the day's puzzle input, one result per part.
+fn solve(solver: &dyn Solver, parts: &[u8]) -> Vec<Result<Timed>> {
+    match InputSource::resolve(solver.day(), None).and_then(|source| source.read()) {
+        Result::Ok(text) => solve_text(solver, parts, &text),
+        Err(err) => failed(parts, err),
+    }
+}
+
+/// Parses `text` once and solves `parts` on it; a parse error is reported for every part.
+fn solve_text(solver: &dyn Solver, parts: &[u8], text: &str) -> Vec<Result<Timed>> {
+    match solver.solve_timed(&mut text.as_bytes(), parts) {
+        Result::Ok(solved) => solved,
+        Err(err) => failed(parts, err),
+    }
+}
+
+fn failed(parts: &[u8], err: Error) -> Vec<Result<Timed>> {
+    let message = format!("{:#}", err);
+    parts.iter().map(|_| Err(anyhow!(message.clone()))).collect()
+}
+
+fn print_table(rows: &[Row]) {
+    let width = rows.iter().map(|row| row.answer.len()).max().unwrap_or(0).max("Answer".len());
+
+    println!("{:<4} {:<4} {:<width$} {:>12} {:>12}", "Day", "Part", "Answer", "Parse", "Solve", width = width);
+    for row in rows {
+        println!(
+            "{:<4} {:<4} {:<width$} {:>12} {:>12}",
+            format!("{:02}", row.day),
+            row.part,
+            row.answer,
+            row.parse.map(|parse| format!("{:.2?}", parse)).unwrap_or_default(),
+            format!("{:.2?}", row.solve),
+            width = width
+        );
+    }
+
+    let parse: Duration = rows.iter().filter_map(|row| row.parse).sum();
+    let solve: Duration = rows.iter().map(|row| row.solve).sum();
+    println!(
+        "{:<4} {:<4} {:<width$} {:>12} {:>12}",
+        "",
+        "",
+        "Total",
+        format!("{:.2?}", parse),
+        format!("{:.2?}", solve),
+        width = width
+    );
+}
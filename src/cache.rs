@@ -0,0 +1,258 @@
+//! Offline cache of puzzle inputs, kept as `<root>/<year>/NN.txt`.
+//!
+//! Every stored input has its SHA-256 recorded in `<root>/<year>/checksums.toml`, so a file that
+//! was edited or truncated by accident is reported instead of silently giving wrong answers.
+//! Missing inputs are pulled through a [`Fetch`] backend: the Advent of Code site itself, or a
+//! local directory standing in for it.
+
+use anyhow::*;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const YEAR: u16 = 2024;
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const CHECKSUMS_FILE: &str = "checksums.toml";
+
+/// Source of puzzle inputs the cache can fill itself from.
+pub trait Fetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Downloads inputs from the Advent of Code site with the user's session cookie.
+pub struct HttpFetch {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetch {
+    pub fn new(session: &str) -> HttpFetch {
+        HttpFetch { base_url: "https://adventofcode.com".to_string(), session: session.trim().to_string() }
+    }
+
+    /// Uses the session cookie from `AOC_SESSION`.
+    pub fn from_env() -> Result<HttpFetch> {
+        let session = std::env::var(SESSION_VAR).with_context(|| format!("{} is not set", SESSION_VAR))?;
+        Ok(HttpFetch::new(&session))
+    }
+
+    /// Points the backend at another server, such as a local mock.
+    pub fn with_base_url(mut self, base_url: &str) -> HttpFetch {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Fetch for HttpFetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "github.com/bsadia/advent_of_code_2024")
+            .call()
+            .with_context(|| format!("cannot fetch {}", url))?;
+
+        response.into_string().with_context(|| format!("cannot read {}", url))
+    }
+}
+
+/// Copies inputs from a directory laid out like the cache, e.g. a backup or a test fixture.
+pub struct DirFetch {
+    dir: PathBuf,
+}
+
+impl DirFetch {
+    pub fn new(dir: impl Into<PathBuf>) -> DirFetch {
+        DirFetch { dir: dir.into() }
+    }
+}
+
+impl Fetch for DirFetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let path = day_path(&self.dir, year, day);
+        fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))
+    }
+}
+
+/// How [`InputCache::get`] came by an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cached {
+    Hit,
+    Fetched,
+}
+
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(root: impl Into<PathBuf>) -> InputCache {
+        InputCache { root: root.into() }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        day_path(&self.root, year, day)
+    }
+
+    /// Reads the cached input of `day`, checked against its recorded checksum. An input without a
+    /// recorded checksum is reported rather than trusted; `get` records one.
+    pub fn read(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.path(year, day);
+        let input = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+        if !self.check(year, day, &input)? {
+            bail!("{} has no recorded checksum; run `aoc fetch {}` to record it", path.display(), day);
+        }
+        Ok(input)
+    }
+
+    /// Returns the cached input of `day`, checked against its recorded checksum, fetching and
+    /// storing it first when it is not cached yet.
+    pub fn get(&self, year: u16, day: u8, backend: &dyn Fetch) -> Result<(String, Cached)> {
+        let path = self.path(year, day);
+        if path.exists() {
+            let input = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+            if !self.check(year, day, &input)? {
+                // Inputs put there by hand are trusted once and guarded from then on.
+                self.record(year, day, &input)?;
+            }
+            return Ok((input, Cached::Hit));
+        }
+
+        let input = backend.fetch(year, day)?;
+        ensure!(!input.trim().is_empty(), "fetched an empty input for {} day {}", year, day);
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, &input).with_context(|| format!("cannot write {}", path.display()))?;
+        self.record(year, day, &input)?;
+
+        Ok((input, Cached::Fetched))
+    }
+
+    // Whether `input` has a recorded checksum for `day`, failing if it does not match it.
+    fn check(&self, year: u16, day: u8, input: &str) -> Result<bool> {
+        match self.checksums(year)?.get(&day_key(day)) {
+            Some(expected) if *expected != checksum(input) => {
+                bail!("{} does not match its checksum; delete it to fetch it again", self.path(year, day).display())
+            }
+            Some(_) => Ok(true),
+            None => Ok(false),
+        }
+    }
+
+    fn record(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        let mut checksums = self.checksums(year)?;
+        checksums.insert(day_key(day), checksum(input));
+        self.save_checksums(year, &checksums)
+    }
+
+    fn checksums_path(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string()).join(CHECKSUMS_FILE)
+    }
+
+    fn checksums(&self, year: u16) -> Result<BTreeMap<String, String>> {
+        let path = self.checksums_path(year);
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let text = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("invalid checksums file {}", path.display()))
+    }
+
+    fn save_checksums(&self, year: u16, checksums: &BTreeMap<String, String>) -> Result<()> {
+        let path = self.checksums_path(year);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, toml::to_string(checksums)?).with_context(|| format!("cannot write {}", path.display()))
+    }
+}
+
+fn day_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("{:02}.txt", day))
+}
+
+fn day_key(day: u8) -> String {
+    format!("{:02}", day)
+}
+
+/// Hex SHA-256 of an input.
+pub fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_once_then_serves_from_cache() -> Result<()> {
+        let remote = temp_dir("remote");
+        fs::create_dir_all(remote.join("2024"))?;
+        fs::write(remote.join("2024/01.txt"), "3   4\n")?;
+
+        let local = temp_dir("local");
+        let cache = InputCache::new(&local);
+        let backend = DirFetch::new(&remote);
+
+        assert_eq!(("3   4\n".to_string(), Cached::Fetched), cache.get(YEAR, 1, &backend)?);
+        fs::remove_dir_all(&remote)?;
+        assert_eq!(("3   4\n".to_string(), Cached::Hit), cache.get(YEAR, 1, &backend)?);
+        assert_eq!("3   4\n", cache.read(YEAR, 1)?);
+
+        fs::write(cache.path(YEAR, 1), "tampered\n")?;
+        assert!(cache.get(YEAR, 1, &backend).is_err());
+        assert!(cache.read(YEAR, 1).unwrap_err().to_string().contains("does not match its checksum"));
+
+        fs::remove_dir_all(&local)?;
+        Ok(())
+    }
+
+    #[test]
+    fn reads_only_recorded_inputs() -> Result<()> {
+        let local = temp_dir("untracked");
+        let cache = InputCache::new(&local);
+        fs::create_dir_all(local.join("2024"))?;
+        fs::write(cache.path(YEAR, 2), "7 6 4\n")?;
+
+        assert!(cache.read(YEAR, 2).unwrap_err().to_string().contains("no recorded checksum"));
+        assert!(!local.join("2024").join(CHECKSUMS_FILE).exists());
+
+        // Fetching trusts the hand-placed input from then on
+        assert_eq!(Cached::Hit, cache.get(YEAR, 2, &DirFetch::new(&local))?.1);
+        assert_eq!("7 6 4\n", cache.read(YEAR, 2)?);
+
+        fs::remove_dir_all(&local)?;
+        Ok(())
+    }
+
+    #[test]
+    fn http_backend_sends_session_cookie() -> Result<()> {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let server = std::thread::spawn(move || -> Result<Vec<String>> {
+            let (stream, _) = listener.accept()?;
+            let request: Vec<String> =
+                BufReader::new(&stream).lines().map_while(|line| line.ok()).take_while(|line| !line.is_empty()).collect();
+            write!(&stream, "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n125 17")?;
+            Ok(request)
+        });
+
+        let input = HttpFetch::new("secret").with_base_url(&base_url).fetch(YEAR, 11)?;
+        let request = server.join().unwrap()?;
+
+        assert_eq!("125 17", input);
+        assert_eq!("GET /2024/day/11/input HTTP/1.1", request[0]);
+        assert!(request.iter().any(|line| line.eq_ignore_ascii_case("cookie: session=secret")));
+        Ok(())
+    }
+}
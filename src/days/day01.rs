@@ -0,0 +1,158 @@
+use crate::parse::{Line, ParseError};
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::cmp::Ordering;
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The left and right location-ID lists.
+    type Input = LocationLists;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        let mut lists = LocationLists::read(reader)?;
+        if !lists.malformed.is_empty() {
+            let count = lists.malformed.len();
+            let first = Error::new(lists.malformed.swap_remove(0));
+            return Err(if count == 1 { first } else { first.context(format!("{} malformed rows", count)) });
+        }
+        ensure!(lists.columns.len() >= 2, "expected two lists of location IDs");
+        Ok(lists)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.distance(0, 1).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.similarity(0, 1).into())
+    }
+}
+
+/// Columns of location IDs, each sorted, and the rows that could not be read.
+#[derive(Clone, Debug, Default)]
+pub struct LocationLists {
+    pub columns: Vec<Vec<i64>>,
+    pub malformed: Vec<ParseError>,
+}
+
+impl LocationLists {
+    /// Reads whitespace-separated columns of IDs a line at a time, reusing one buffer, so exports
+    /// of any length only cost their numbers. The first good row fixes the number of columns;
+    /// rows that do not match it or hold anything but numbers are skipped and kept in `malformed`.
+    pub fn read<R: BufRead>(mut reader: R) -> Result<LocationLists> {
+        let mut lists = LocationLists::default();
+        let mut text = String::new();
+        let mut row = Vec::new();
+
+        for number in 1.. {
+            text.clear();
+            if reader.read_line(&mut text)? == 0 {
+                break;
+            }
+            let line = Line::new(number, text.trim_end());
+            if line.text.is_empty() {
+                continue;
+            }
+
+            row.clear();
+            match read_row(line, lists.columns.len(), &mut row) {
+                Ok(()) if lists.columns.is_empty() => lists.columns = row.iter().map(|&id| vec![id]).collect(),
+                Ok(()) => lists.columns.iter_mut().zip(&row).for_each(|(column, &id)| column.push(id)),
+                Err(error) => lists.malformed.push(error),
+            }
+        }
+
+        for column in &mut lists.columns {
+            column.sort_unstable();
+        }
+        Ok(lists)
+    }
+
+    /// Total distance between columns `a` and `b`, pairing their IDs smallest first.
+    pub fn distance(&self, a: usize, b: usize) -> i64 {
+        self.columns[a].iter().zip(&self.columns[b]).map(|(left, right)| (left - right).abs()).sum()
+    }
+
+    /// Similarity of column `a` to column `b`: every ID of `a` times how often it appears in `b`.
+    pub fn similarity(&self, a: usize, b: usize) -> i64 {
+        let (left, right) = (&self.columns[a], &self.columns[b]);
+
+        // Both columns are sorted, so equal IDs meet in a single merge
+        let (mut i, mut j) = (0, 0);
+        let mut total = 0;
+        while i < left.len() && j < right.len() {
+            match left[i].cmp(&right[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    let id = left[i];
+                    let in_right = right[j..].iter().take_while(|&&other| other == id).count();
+                    let in_left = left[i..].iter().take_while(|&&other| other == id).count();
+                    total += id * (in_left * in_right) as i64;
+                    i += in_left;
+                    j += in_right;
+                }
+            }
+        }
+        total
+    }
+}
+
+// Parses the IDs of `line` into `row`, expecting `width` of them, or at least two when it is 0.
+fn read_row(line: Line, width: usize, row: &mut Vec<i64>) -> Result<(), ParseError> {
+    for field in line.text.split_ascii_whitespace() {
+        row.push(line.parse(field)?);
+    }
+    match width {
+        0 if row.len() < 2 => Err(line.error(line.text, "expected at least two numbers")),
+        0 => Ok(()),
+        _ if row.len() != width => Err(line.error(line.text, format!("expected {} numbers", width))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/01/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(11, Day01.part1(&Day01.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(31, Day01.part2(&Day01.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reads_any_columns_and_reports_bad_rows() -> Result<()> {
+        let lists = LocationLists::read("3 4 10\n4 3 x\n2 5 1\n\n1 3\n3 9 3\n".as_bytes())?;
+
+        assert_eq!(vec![vec![2, 3, 3], vec![4, 5, 9], vec![1, 3, 10]], lists.columns);
+        let malformed: Vec<_> = lists.malformed.iter().map(|error| (error.line, error.column)).collect();
+        assert_eq!(vec![(2, 5), (5, 1)], malformed);
+        assert_eq!(6, lists.distance(1, 2));
+        assert_eq!(6, lists.similarity(0, 2));
+
+        let err = Day01.parse("1 2\n3\n4 x\n".as_bytes()).unwrap_err();
+        assert_eq!("2 malformed rows", err.to_string());
+        assert_eq!("line 2, column 1: '3': expected 2 numbers", err.root_cause().to_string());
+        Ok(())
+    }
+}
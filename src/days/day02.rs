@@ -0,0 +1,153 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use serde::Deserialize;
+use std::io::BufRead;
+
+/// What makes a report safe; [`Day02::PUZZLE`] holds the puzzle's rules, and any field a
+/// parameter table leaves out keeps its value.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default)]
+pub struct Day02 {
+    /// Smallest change allowed between adjacent levels.
+    pub min_step: i32,
+    /// Largest change allowed between adjacent levels.
+    pub max_step: i32,
+    /// Number of levels the problem dampener may drop from a report.
+    pub tolerance: usize,
+}
+
+impl Default for Day02 {
+    fn default() -> Day02 {
+        Day02::PUZZLE
+    }
+}
+
+impl Solution for Day02 {
+    /// One report of levels per line.
+    type Input = Vec<Vec<i32>>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        let mut reports = Vec::new();
+        for (index, text) in reader.lines().enumerate() {
+            let text = text?;
+            let line = Line::new(index + 1, &text);
+            reports.push(line.parse_each(text.split_whitespace())?);
+        }
+        Ok(reports)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(self, input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(self, input).into())
+    }
+}
+
+impl Day02 {
+    pub const PUZZLE: Day02 = Day02 { min_step: 1, max_step: 3, tolerance: 1 };
+
+    /// The fewest level indices to drop from `report` to make it safe, if `tolerance` of them are
+    /// enough. A single pass over the levels, looking back at most `tolerance + 1` of them.
+    pub fn dampen(&self, report: &[i32], tolerance: usize) -> Option<Vec<usize>> {
+        [1, -1].into_iter().filter_map(|sign| self.dampen_towards(report, tolerance, sign)).min_by_key(Vec::len)
+    }
+
+    // `dampen` for levels that all rise (`sign` 1) or all fall (`sign` -1).
+    fn dampen_towards(&self, report: &[i32], tolerance: usize, sign: i32) -> Option<Vec<usize>> {
+        let n = report.len();
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        // Fewest levels before `i` dropped when keeping it, and the level kept just before it
+        let mut drops = vec![usize::MAX; n];
+        let mut previous = vec![None; n];
+        for i in 0..n {
+            if i <= tolerance {
+                drops[i] = i; // Drop every level before it
+            }
+            for j in i.saturating_sub(tolerance + 1)..i {
+                let step = sign * (report[i] - report[j]);
+                if drops[j] == usize::MAX || !(self.min_step..=self.max_step).contains(&step) {
+                    continue;
+                }
+                if drops[j] + (i - j - 1) < drops[i] {
+                    drops[i] = drops[j] + (i - j - 1);
+                    previous[i] = Some(j);
+                }
+            }
+        }
+
+        // The last level kept, counting the levels dropped after it
+        let last = (n.saturating_sub(tolerance + 1)..n)
+            .filter(|&i| drops[i] != usize::MAX)
+            .min_by_key(|&i| drops[i] + (n - 1 - i))?;
+        if drops[last] + (n - 1 - last) > tolerance {
+            return None;
+        }
+
+        let mut kept = vec![false; n];
+        let mut level = Some(last);
+        while let Some(i) = level {
+            kept[i] = true;
+            level = previous[i];
+        }
+        Some((0..n).filter(|&i| !kept[i]).collect())
+    }
+}
+
+fn part1(rules: &Day02, reports: &[Vec<i32>]) -> usize {
+    reports.iter().filter(|report| rules.dampen(report, 0).is_some()).count()
+}
+
+fn part2(rules: &Day02, reports: &[Vec<i32>]) -> usize {
+    // Reports the problem dampener can make safe, by dropping up to `tolerance` levels
+    reports.iter().filter(|report| rules.dampen(report, rules.tolerance).is_some()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: Day02 = Day02::PUZZLE;
+    const TEST: &str = include_str!("../../examples/02/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(2, PUZZLE.part1(&PUZZLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(4, PUZZLE.part2(&PUZZLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn dampener_reports_dropped_levels() {
+        assert_eq!(Some(vec![]), PUZZLE.dampen(&[7, 6, 4, 2, 1], 0));
+        assert_eq!(Some(vec![1]), PUZZLE.dampen(&[1, 5, 2, 3, 4], 1));
+        assert_eq!(Some(vec![3]), PUZZLE.dampen(&[9, 7, 6, 9, 4], 1));
+        assert_eq!(None, PUZZLE.dampen(&[1, 2, 7, 8, 9], 1));
+
+        // Two levels out of place need a tolerance of two
+        assert_eq!(None, PUZZLE.dampen(&[1, 9, 2, 9, 3], 1));
+        assert_eq!(Some(vec![1, 3]), PUZZLE.dampen(&[1, 9, 2, 9, 3], 2));
+        assert_eq!(Some(vec![0, 1]), PUZZLE.dampen(&[5, 1, 10, 11], 2));
+
+        let wide = Day02 { max_step: 5, ..PUZZLE };
+        assert_eq!(Some(vec![]), wide.dampen(&[1, 2, 7, 8, 9], 0));
+    }
+}
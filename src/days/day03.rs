@@ -0,0 +1,222 @@
+use crate::{read_input, Answer, Solution};
+use anyhow::*;
+use serde::Deserialize;
+use std::fmt;
+use std::io::BufRead;
+
+/// How strictly instructions are recognized; [`Day03::PUZZLE`] takes them exactly as written.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Day03 {
+    /// Whether blanks may surround the arguments of an instruction, as in `mul( 2 , 4 )`.
+    pub lenient: bool,
+}
+
+impl Day03 {
+    pub const PUZZLE: Day03 = Day03 { lenient: false };
+}
+
+/// An uncorrupted instruction found in the memory dump.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+/// Names the scanner looks for in front of a `(`; [`Instruction::from_call`] gives their meaning.
+const NAMES: [&str; 3] = ["mul", "do", "don't"];
+
+impl Instruction {
+    /// The instruction `name` stands for when called with `args`, if any.
+    pub fn from_call(name: &str, args: &[i32]) -> Option<Instruction> {
+        match (name, args) {
+            ("mul", &[x, y]) => Some(Instruction::Mul(x, y)),
+            ("do", []) => Some(Instruction::Do),
+            ("don't", []) => Some(Instruction::Dont),
+            _ => None,
+        }
+    }
+}
+
+/// Written as in the memory dump.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Mul(x, y) => write!(f, "mul({},{})", x, y),
+            Instruction::Do => write!(f, "do()"),
+            Instruction::Dont => write!(f, "don't()"),
+        }
+    }
+}
+
+/// An instruction and the byte offset of its name in the memory dump.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+impl Solution for Day03 {
+    /// Every instruction of the memory dump, in order.
+    type Input = Vec<Token>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        Ok(scan(&read_input(reader)?, self.lenient))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(Interpreter::new(false).run(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(Interpreter::new(true).run(input).into())
+    }
+}
+
+/// Every instruction in `memory`, skipping the corrupted bytes around them. An instruction is a
+/// known name, `(`, its arguments (numbers of one to three digits) separated by `,`, and `)`;
+/// `lenient` also lets blanks surround the arguments.
+pub fn scan(memory: &str, lenient: bool) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    for (open, _) in memory.match_indices('(') {
+        let Some(name) = NAMES.iter().find(|name| memory[..open].ends_with(*name)) else {
+            continue;
+        };
+        let args = scan_args(&memory[open + 1..], lenient);
+        let instruction = args.and_then(|args| Instruction::from_call(name, &args));
+        if let Some(instruction) = instruction {
+            tokens.push(Token { offset: open - name.len(), instruction });
+        }
+    }
+    tokens
+}
+
+// The arguments of a call up to its `)`, `text` starting just after the `(`.
+fn scan_args(text: &str, lenient: bool) -> Option<Vec<i32>> {
+    let blanks = |text: &str| if lenient { text.trim_start_matches([' ', '\t']).len() } else { text.len() };
+    let mut rest = &text[text.len() - blanks(text)..];
+    let mut args = Vec::new();
+    if rest.starts_with(')') {
+        return Some(args);
+    }
+
+    loop {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if !(1..=3).contains(&digits) {
+            return None;
+        }
+        args.push(rest[..digits].parse().ok()?);
+        rest = &rest[digits..];
+        rest = &rest[rest.len() - blanks(rest)..];
+
+        if rest.starts_with(')') {
+            return Some(args);
+        }
+        rest = rest.strip_prefix(',')?;
+        rest = &rest[rest.len() - blanks(rest)..];
+    }
+}
+
+/// Executes instructions, adding up the products of the `mul`s while they are enabled.
+#[derive(Clone, Copy, Debug)]
+pub struct Interpreter {
+    /// Whether `do()` and `don't()` switch the `mul`s on and off; part 1 ignores them.
+    pub conditionals: bool,
+    pub enabled: bool,
+    pub total: i64,
+}
+
+/// One executed instruction and the state of the interpreter after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub token: Token,
+    pub enabled: bool,
+    pub total: i64,
+}
+
+impl Interpreter {
+    pub fn new(conditionals: bool) -> Interpreter {
+        Interpreter { conditionals, enabled: true, total: 0 }
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Do if self.conditionals => self.enabled = true,
+            Instruction::Dont if self.conditionals => self.enabled = false,
+            Instruction::Mul(x, y) if self.enabled => self.total += x as i64 * y as i64,
+            _ => {}
+        }
+    }
+
+    /// Executes every token, returning the total.
+    pub fn run(mut self, tokens: &[Token]) -> i64 {
+        for token in tokens {
+            self.execute(token.instruction);
+        }
+        self.total
+    }
+
+    /// Executes every token, recording the state after each one.
+    pub fn trace(mut self, tokens: &[Token]) -> Vec<Step> {
+        let mut steps = Vec::with_capacity(tokens.len());
+        for &token in tokens {
+            self.execute(token.instruction);
+            steps.push(Step { token, enabled: self.enabled, total: self.total });
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: Day03 = Day03::PUZZLE;
+    const TEST_PART1: &str = include_str!("../../examples/03/part1.txt");
+    const TEST: &str = include_str!("../../examples/03/part2.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(161, PUZZLE.part1(&PUZZLE.parse(TEST_PART1.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(48, PUZZLE.part2(&PUZZLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn scans_with_offsets_and_whitespace_modes() {
+        let memory = "xmul(2,4)&mul( 3 , 7 )don't()_mul(1234,5)do()mul(8,5)";
+
+        let strict: Vec<(usize, String)> =
+            scan(memory, false).iter().map(|token| (token.offset, token.instruction.to_string())).collect();
+        let expected = [(1, "mul(2,4)"), (22, "don't()"), (41, "do()"), (45, "mul(8,5)")];
+        assert_eq!(expected.map(|(offset, text)| (offset, text.to_string())).to_vec(), strict);
+
+        let lenient = scan(memory, true);
+        assert_eq!(Token { offset: 10, instruction: Instruction::Mul(3, 7) }, lenient[1]);
+        assert_eq!(5, lenient.len());
+    }
+
+    #[test]
+    fn traces_execution() {
+        let tokens = scan("mul(2,4)don't()mul(5,5)do()mul(8,5)", false);
+        let steps = Interpreter::new(true).trace(&tokens);
+        let totals: Vec<(bool, i64)> = steps.iter().map(|step| (step.enabled, step.total)).collect();
+
+        assert_eq!(vec![(true, 8), (false, 8), (false, 8), (true, 8), (true, 48)], totals);
+        assert_eq!(73, Interpreter::new(false).run(&tokens));
+    }
+}
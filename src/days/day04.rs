@@ -0,0 +1,63 @@
+use crate::grid::Grid;
+use crate::word_search::{find_words, Orientation, Stencil};
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// The letter grid.
+    type Input = Grid<char>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+// The X-shaped MAS of part 2, in the orientation the puzzle draws it.
+const X_MAS: &str = "M.S\n.A.\nM.S";
+
+fn part1(grid: &Grid<char>) -> usize {
+    find_words(grid, &["XMAS"]).len()
+}
+
+fn part2(grid: &Grid<char>) -> Result<usize> {
+    let stencil = Stencil::parse(X_MAS, '.')?;
+    Ok(stencil.find(grid, &Orientation::all(true, false)).len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/04/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(18, Day04.part1(&Day04.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(9, Day04.part2(&Day04.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
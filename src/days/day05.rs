@@ -0,0 +1,288 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::io::BufRead;
+
+pub struct Day05;
+
+/// `before|after`: if an update has both pages, `before` must be printed first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rule {
+    pub before: i32,
+    pub after: i32,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}|{}", self.before, self.after)
+    }
+}
+
+/// A rule an update breaks, with the positions of its two pages in the update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: Rule,
+    pub before_at: usize,
+    pub after_at: usize,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is broken: {} is page {} of the update but {} is page {}",
+            self.rule,
+            self.rule.after,
+            self.after_at + 1,
+            self.rule.before,
+            self.before_at + 1
+        )
+    }
+}
+
+/// The ordering rules, indexed for looking up whether any two pages are ordered.
+///
+/// The rules over every page together may well be cyclic; only the rules between the pages of
+/// one update have to form an order.
+#[derive(Clone, Debug, Default)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+    index: HashSet<Rule>,
+}
+
+impl RuleSet {
+    /// The set of `rules`, ignoring repeats.
+    pub fn new(rules: impl IntoIterator<Item = Rule>) -> RuleSet {
+        let mut set = RuleSet::default();
+        for rule in rules {
+            if set.index.insert(rule) {
+                set.rules.push(rule);
+            }
+        }
+        set
+    }
+
+    /// The rules in the order they were given.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Whether a rule puts `before` ahead of `after`.
+    pub fn requires(&self, before: i32, after: i32) -> bool {
+        self.index.contains(&Rule { before, after })
+    }
+
+    /// Every rule `update` breaks, by the positions of the pages it has the wrong way round.
+    pub fn violations(&self, update: &[i32]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for (after_at, &after) in update.iter().enumerate() {
+            for (before_at, &before) in update.iter().enumerate().skip(after_at + 1) {
+                if self.requires(before, after) {
+                    violations.push(Violation { rule: Rule { before, after }, before_at, after_at });
+                }
+            }
+        }
+        violations
+    }
+
+    pub fn is_ordered(&self, update: &[i32]) -> bool {
+        for (i, &after) in update.iter().enumerate() {
+            if update[i + 1..].iter().any(|&before| self.requires(before, after)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Pages of `update` whose rules go round in a circle, each required before the next and the
+    /// last before the first, or `None` if its rules can all be kept.
+    pub fn find_cycle(&self, update: &[i32]) -> Option<Vec<i32>> {
+        // 0 unvisited, 1 on the current path, 2 done
+        let mut state = vec![0u8; update.len()];
+        let mut path = Vec::new();
+        for start in 0..update.len() {
+            if state[start] == 0 {
+                if let Some(cycle) = self.cycle_from(update, start, &mut state, &mut path) {
+                    return Some(cycle.iter().map(|&i| update[i]).collect());
+                }
+            }
+        }
+        None
+    }
+
+    // Depth-first search from `page` for a way back onto `path`.
+    fn cycle_from(
+        &self,
+        update: &[i32],
+        page: usize,
+        state: &mut [u8],
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        state[page] = 1;
+        path.push(page);
+        for next in 0..update.len() {
+            if !self.requires(update[page], update[next]) {
+                continue;
+            }
+            match state[next] {
+                0 => {
+                    if let Some(cycle) = self.cycle_from(update, next, state, path) {
+                        return Some(cycle);
+                    }
+                }
+                1 => return Some(path[path.iter().position(|&i| i == next)?..].to_vec()),
+                _ => {}
+            }
+        }
+        path.pop();
+        state[page] = 2;
+        None
+    }
+
+    /// `update` rearranged to keep every rule between its pages. Pages no rule orders against each
+    /// other keep their relative order, so the result only depends on the update and the rules.
+    pub fn order(&self, update: &[i32]) -> Result<Vec<i32>> {
+        let mut required_before = vec![0; update.len()];
+        for (i, &after) in update.iter().enumerate() {
+            required_before[i] = update.iter().filter(|&&before| self.requires(before, after)).count();
+        }
+
+        // Kahn's algorithm, always taking the earliest free page of the update
+        let mut free: BTreeSet<usize> = (0..update.len()).filter(|&i| required_before[i] == 0).collect();
+        let mut ordered = Vec::with_capacity(update.len());
+        while let Some(i) = free.pop_first() {
+            ordered.push(update[i]);
+            for (j, &after) in update.iter().enumerate() {
+                if self.requires(update[i], after) {
+                    required_before[j] -= 1;
+                    if required_before[j] == 0 {
+                        free.insert(j);
+                    }
+                }
+            }
+        }
+
+        if ordered.len() < update.len() {
+            let cycle = self.find_cycle(update).unwrap_or_default();
+            let pages: Vec<String> = cycle.iter().chain(cycle.first()).map(i32::to_string).collect();
+            bail!("the rules for these pages go round in a circle: {}", pages.join(" -> "));
+        }
+        Ok(ordered)
+    }
+}
+
+impl Solution for Day05 {
+    /// The ordering rules and the updates.
+    type Input = (RuleSet, Vec<Vec<i32>>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+fn part1((rules, updates): &(RuleSet, Vec<Vec<i32>>)) -> i32 {
+    updates.iter().filter(|update| rules.is_ordered(update)).map(|update| update[update.len() / 2]).sum()
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(RuleSet, Vec<Vec<i32>>)> {
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+
+    let blank_line_index = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .context("no blank line between the rules and the updates")?;
+
+    let mut rules = Vec::new();
+    for (index, rule) in lines[..blank_line_index].iter().enumerate() {
+        let line = Line::new(index + 1, rule);
+        let (before, after) = line.split_once("|")?;
+        rules.push(Rule { before: line.parse(before)?, after: line.parse(after)? });
+    }
+
+    let mut updates_vec: Vec<Vec<i32>> = Vec::new();
+    for (index, update) in lines.iter().enumerate().skip(blank_line_index + 1) {
+        if !update.trim().is_empty() {
+            updates_vec.push(Line::new(index + 1, update).parse_each(update.split(','))?);
+        }
+    }
+
+    Ok((RuleSet::new(rules), updates_vec))
+}
+
+fn part2((rules, updates): &(RuleSet, Vec<Vec<i32>>)) -> Result<i32> {
+    let mut middle_sum = 0;
+
+    for (index, update) in updates.iter().enumerate() {
+        if !rules.is_ordered(update) {
+            let corrected = rules
+                .order(update)
+                .with_context(|| format!("update {} cannot be ordered", index + 1))?;
+            middle_sum += corrected[corrected.len() / 2];
+        }
+    }
+
+    Ok(middle_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/05/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(143, Day05.part1(&Day05.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(123, Day05.part2(&Day05.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn explains_and_reorders() -> Result<()> {
+        let (rules, _) = Day05.parse(TEST.as_bytes())?;
+
+        let violations = rules.violations(&[61, 13, 29]);
+        let broken = Violation { rule: Rule { before: 29, after: 13 }, before_at: 2, after_at: 1 };
+        assert_eq!(vec![broken], violations);
+        assert_eq!("29|13 is broken: 13 is page 2 of the update but 29 is page 3", violations[0].to_string());
+        assert_eq!(vec![97, 75, 47, 29, 13], rules.order(&[97, 13, 75, 29, 47])?);
+        assert!(rules.violations(&[75, 47, 61, 53, 29]).is_empty());
+
+        // Pages no rule holds back keep their place
+        assert_eq!(vec![1, 2, 97, 75], rules.order(&[1, 75, 2, 97])?);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let rules = RuleSet::new([(1, 2), (2, 3), (3, 1)].map(|(before, after)| Rule { before, after }));
+
+        assert_eq!(Some(vec![3, 1, 2]), rules.find_cycle(&[3, 1, 2]));
+        assert_eq!(None, rules.find_cycle(&[1, 2]));
+        let error = rules.order(&[4, 3, 2, 1]).unwrap_err();
+        assert_eq!("the rules for these pages go round in a circle: 3 -> 1 -> 2 -> 3", error.to_string());
+    }
+}
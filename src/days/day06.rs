@@ -0,0 +1,176 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::{Answer, Solution};
+use anyhow::*;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day06;
+
+/// The lab map with the guard's starting position and facing.
+pub struct Lab {
+    pub map: Grid<char>,
+    pub start: Point,
+    pub dir: Direction,
+}
+
+impl Solution for Day06 {
+    type Input = Lab;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_map(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn parse_map<R: BufRead>(reader: R) -> Result<Lab> {
+    let map = Grid::parse(reader)?;
+
+    let Some((start, dir)) = map.iter().find_map(|(point, &ch)| Some((point, Direction::from_char(ch)?))) else {
+        bail!("the map has no guard")
+    };
+    Ok(Lab { map, start, dir })
+}
+
+// Walks the guard off the map, marking every visited tile with 'X'.
+fn walk(lab: &Lab) -> Grid<char> {
+    let mut map = lab.map.clone();
+    let mut pos = lab.start;
+    let mut dir = lab.dir;
+
+    while map.contains(pos) {
+        map[pos] = 'X';
+
+        let next = pos.step(dir);
+
+        if map.get(next) == Some(&'#') {
+            dir = dir.turn_right();
+            continue;
+        }
+
+        pos = next;
+    }
+    map
+}
+
+fn part1(lab: &Lab) -> usize {
+    let map = walk(lab);
+
+    map.find_all(&'X').count()
+}
+
+/// For every tile and facing, where the guard walking that way stops in front of an obstacle,
+/// or `None` if the walk leaves the map.
+struct JumpTable {
+    stops: Grid<[Option<Point>; 4]>,
+}
+
+impl JumpTable {
+    fn new(map: &Grid<char>) -> JumpTable {
+        let mut stops = Grid::new(map.width(), map.height(), [None; 4]);
+        let points: Vec<Point> = map.points().collect();
+        for dir in Direction::ALL {
+            // Work back from the edge the guard walks towards, so the tile ahead is always done
+            let order: Box<dyn Iterator<Item = &Point>> = match dir {
+                Direction::Up | Direction::Left => Box::new(points.iter()),
+                Direction::Down | Direction::Right => Box::new(points.iter().rev()),
+            };
+            for &point in order {
+                let next = point.step(dir);
+                stops[point][dir as usize] = match map.get(next) {
+                    None => None,
+                    Some('#') => Some(point),
+                    Some(_) => stops[next][dir as usize],
+                };
+            }
+        }
+        JumpTable { stops }
+    }
+
+    /// Where the guard walking from `pos` towards `dir` stops, with an extra obstruction at `ob`.
+    fn stop(&self, pos: Point, dir: Direction, ob: Point) -> Option<Point> {
+        let stop = self.stops[pos][dir as usize];
+
+        // How far ahead the obstruction is, if it is straight ahead at all
+        let (offset, delta) = (ob - pos, dir.delta());
+        let ahead = offset.row * delta.col == offset.col * delta.row;
+        let distance = offset.row * delta.row + offset.col * delta.col;
+        if ahead && distance > 0 && stop.is_none_or(|stop| distance as usize <= pos.manhattan(stop)) {
+            return Some(pos + delta * (distance - 1));
+        }
+        stop
+    }
+}
+
+// Whether the guard walks in circles once `ob` is added, jumping from obstacle to obstacle.
+fn loops(jumps: &JumpTable, lab: &Lab, ob: Point) -> bool {
+    let mut turns = HashSet::new();
+    let (mut pos, mut dir) = (lab.start, lab.dir);
+
+    while let Some(stop) = jumps.stop(pos, dir, ob) {
+        if !turns.insert((stop, dir)) {
+            return true;
+        }
+        pos = stop;
+        dir = dir.turn_right();
+    }
+    false
+}
+
+fn part2(lab: &Lab) -> usize {
+    let jumps = JumpTable::new(&lab.map);
+
+    // Only an obstruction on the original path changes it, and the guard's own tile is off limits
+    let obstacles: Vec<Point> = walk(lab).find_all(&'X').filter(|&ob| ob != lab.start).collect();
+
+    obstacles.par_iter().filter(|&&ob| loops(&jumps, lab, ob)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/06/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(41, Day06.part1(&Day06.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(6, Day06.part2(&Day06.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn jumps_stop_in_front_of_obstacles() -> Result<()> {
+        let lab = Day06.parse(TEST.as_bytes())?;
+        let jumps = JumpTable::new(&lab.map);
+        let nowhere = Point::new(-1, -1);
+
+        assert_eq!(Some(Point::new(1, 4)), jumps.stop(lab.start, Direction::Up, nowhere));
+        assert_eq!(Some(Point::new(4, 4)), jumps.stop(lab.start, Direction::Up, Point::new(3, 4)));
+        assert_eq!(Some(Point::new(1, 4)), jumps.stop(lab.start, Direction::Up, Point::new(7, 4)));
+        assert_eq!(None, jumps.stop(lab.start, Direction::Right, nowhere));
+        assert_eq!(Some(Point::new(6, 7)), jumps.stop(lab.start, Direction::Right, Point::new(6, 8)));
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    /// Each equation as its test value and operands.
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1(equations: &[(i64, Vec<i64>)]) -> i64 {
+    process_input(equations, 2)
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<(i64, Vec<i64>)>> {
+    let mut equations = Vec::new();
+
+    for (index, text) in reader.lines().enumerate() {
+        let text = text?;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let line = Line::new(index + 1, &text);
+        let (test_value, numbers) = line.split_once(":")?;
+
+        let test_value: i64 = line.parse(test_value)?;
+        let numbers: Vec<i64> = line.parse_each(numbers.split_whitespace())?;
+        if numbers.is_empty() {
+            return Err(line.error(line.text, "expected numbers after ':'").into());
+        }
+        equations.push((test_value, numbers));
+    }
+
+    Ok(equations)
+}
+
+fn process_input(equations: &[(i64, Vec<i64>)], operators: i32) -> i64 {
+    let mut total_test_value = 0;
+
+    for (test_value, numbers) in equations {
+        // Evaluate all possible combinations of the operators
+        if find_valid_equation(*test_value, numbers, operators).is_some() {
+            total_test_value += test_value;
+        }
+    }
+
+    total_test_value
+}
+
+fn part2(equations: &[(i64, Vec<i64>)]) -> i64 {
+    process_input(equations, 3)
+}
+
+fn find_valid_equation(test_value: i64, numbers: &[i64], operators: i32) -> Option<String> {
+    let n = numbers.len();
+    let num_operators = n - 1;
+
+    // Generate all combinations of '+', '*', and '||' operators
+    // processed bitwise to determine the operator at each position
+    for mask in 0..operators.pow(num_operators as u32) {
+        let mut result = numbers[0];
+        let mut equation = format!("{}", numbers[0]);
+
+        let mut current_mask = mask;
+        for (_, &num) in numbers.iter().enumerate().skip(1) {
+            let operator = current_mask % operators; // Extract the operator (0 = +, 1 = *, 2 = ||)
+            current_mask /= operators;
+
+            match operator {
+                0 => {
+                    result += num;
+                    equation.push_str(&format!(" + {}", num));
+                }
+                1 => {
+                    result *= num;
+                    equation.push_str(&format!(" * {}", num));
+                }
+                2 => {
+                    let concatenated = concat_numbers(result, num);
+                    equation = format!("{} || {}", equation, num);
+                    result = concatenated;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        if result == test_value {
+            return Some(format!("{} = {}", equation, test_value));
+        }
+    }
+
+    None
+}
+
+fn concat_numbers(left: i64, right: i64) -> i64 {
+    let right_digits = count_digits(right);
+    left * 10_i64.pow(right_digits) + right
+}
+
+fn count_digits(mut num: i64) -> u32 {
+    let mut count = 0;
+    while num > 0 {
+        count += 1;
+        num /= 10;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/07/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(3749, Day07.part1(&Day07.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(11387, Day07.part2(&Day07.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_equations_without_numbers() {
+        let error = Day07.parse("190: 10 19\n5:\n".as_bytes()).unwrap_err();
+
+        assert_eq!("line 2, column 1: '5:': expected numbers after ':'", error.to_string());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// The antenna map.
+    type Input = Grid<char>;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    find_antinode_locations(grid, true)
+}
+
+fn find_antinode_locations(grid: &Grid<char>, part_1: bool) -> usize {
+    let frequencies: HashSet<char> = grid.iter().map(|(_, &v)| v).filter(|&v| v != '.').collect();
+
+    let mut antinode_locations = HashSet::new();
+
+    for frequency in &frequencies {
+        let locations: Vec<Point> = grid.find_all(frequency).collect();
+
+        for i in 0..locations.len() {
+            for j in (i + 1)..locations.len() {
+                let l = locations[i];
+                let r = locations[j];
+                // Calculate the slope
+                let slope = l - r;
+
+                if part_1 {
+                    for p in [l + slope, r - slope] {
+                        if grid.contains(p) {
+                            antinode_locations.insert(p);
+                        }
+                    }
+                } else {
+                    // Walk away from both antennas, each antenna being an antinode itself
+                    for (mut p, step) in [(l, slope), (r, slope * -1)] {
+                        while grid.contains(p) {
+                            antinode_locations.insert(p);
+                            p += step;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    antinode_locations.len()
+}
+fn part2(grid: &Grid<char>) -> usize {
+    find_antinode_locations(grid, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/08/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(14, Day08.part1(&Day08.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(34, Day08.part2(&Day08.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
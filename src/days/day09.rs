@@ -0,0 +1,209 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    /// Every disk block: the ID of the file in it, or `None` when free.
+    type Input = Vec<Option<usize>>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_disk_map(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn parse_disk_map<R: BufRead>(reader: R) -> Result<Vec<Option<usize>>> {
+    let mut result = Vec::new(); // To store the parsed representation
+    let mut file_id = 0; // Start file ID from 0
+
+    for (index, disk_map) in reader.lines().enumerate() {
+        let disk_map = disk_map?; // Read each line as a string
+        let line = Line::new(index + 1, &disk_map);
+
+        // Digits alternate between file sizes and free space sizes
+        for i in 0..disk_map.trim_end().len() {
+            let size = line.digit(i)? as usize;
+
+            if i % 2 == 0 {
+                // Push file ID into the result for `size` times
+                result.extend(std::iter::repeat_n(Some(file_id), size));
+                file_id += 1;
+            } else {
+                // Push `None` into the result for `size` times
+                result.extend(std::iter::repeat_n(None, size));
+            }
+        }
+    }
+
+    ensure!(!result.is_empty(), "disk map has no blocks");
+    Ok(result)
+}
+
+fn move_one_file_at_time(disk_map: &[Option<usize>]) -> usize {
+    let mut map_copy = disk_map.to_vec();
+    let mut left = 0; // Pointer to find the first free space
+
+    let mut right = map_copy.len() - 1; // Pointer to find the last file block
+
+    while left < right {
+        // Move `left` to the next free space (None)
+        while left < map_copy.len() && map_copy[left].is_some() {
+            left += 1;
+        }
+
+        // Move `right` to the previous file block (Some(file_id))
+        while right > 0 && map_copy[right].is_none() {
+            right -= 1;
+        }
+
+        // If we found both an empty space and a file block, swap them
+        if left < right && map_copy[left].is_none() && map_copy[right].is_some() {
+            map_copy.swap(left, right);
+            left += 1; // Move to the next free space
+            right -= 1; // Move to the previous file block
+        }
+    }
+
+    checksum(&map_copy)
+}
+
+fn part1(disk_map: &[Option<usize>]) -> usize {
+    move_one_file_at_time(disk_map)
+}
+
+fn move_file_blocks(disk_map: &mut [Option<usize>]) -> usize {
+    // Start pointer for free space; a disk without gaps has nothing to move
+    let Some(left) = disk_map.iter().position(|x| x.is_none()) else {
+        return checksum(disk_map);
+    };
+    let mut right = disk_map.len() - 1; // Start pointer for file blocks
+    let max_file_id = disk_map.iter().filter_map(|&x| x).max().unwrap_or(0);
+    let mut moved = vec![false; max_file_id]; // Track moved file IDs
+
+    while left < right {
+        if let Some(file_id) = disk_map[right] {
+            let mut need = 0;
+            for idx in (0..=right).rev() {
+                if disk_map[idx] == Some(file_id) {
+                    need += 1;
+                } else {
+                    break;
+                }
+            }
+
+            if moved[file_id - 1] {
+                right -= need;
+                continue;
+            }
+
+            let mut free_space = left; // Start looking for free space from `left`
+
+            loop {
+                // 1. Find the next free space (skip the occupied spaces)
+                while disk_map[free_space].is_some() {
+                    free_space += 1; // Skip occupied space
+                }
+
+                // 2. Check if there is enough space to move the file (not enough space, move to next file)
+                if free_space > right - need {
+                    right -= need; // Move the right pointer to next block (file)
+                    break;
+                }
+
+                // 3. Count how many free spaces are available from the current free_space
+                let mut available = 0;
+                for slot in &disk_map[free_space..] {
+                    if slot.is_none() {
+                        available += 1;
+                    } else {
+                        break; // Stop once we hit an occupied space
+                    }
+                }
+
+                // 4. Check if the available space is enough to fit the current file (need)
+                if available >= need {
+                    // Move the file to the free space
+                    // Place the file ID in the free space
+                    disk_map[free_space..free_space + need].fill(Some(file_id));
+
+                    // Clear the original positions of the file from the `right` end
+                    // Remove the original file ID
+                    disk_map[right - need + 1..=right].fill(None);
+
+                    // Update the `right` pointer
+                    right -= need;
+                    moved[file_id - 1] = true; // Mark the file as moved
+                    break;
+                } else {
+                    // Not enough space found, move to the next free space
+                    free_space += available; // Skip to the next available space
+                }
+            }
+        } else {
+            right -= 1; // Skip if already free space
+        }
+    }
+
+    checksum(disk_map)
+}
+
+/// Sum of position * file_id over every file block.
+fn checksum(disk_map: &[Option<usize>]) -> usize {
+    disk_map
+        .iter()
+        .enumerate()
+        .filter_map(|(position, &file_id)| file_id.map(|id| position * id))
+        .sum()
+}
+
+fn part2(disk_map: &[Option<usize>]) -> usize {
+    move_file_blocks(&mut disk_map.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/09/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(1928, Day09.part1(&Day09.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(2858, Day09.part2(&Day09.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn handles_full_and_empty_disks() -> Result<()> {
+        // Files 0, 1 and 2 back to back: blocks 0 1 1 2 2 2
+        let full = Day09.parse("10203".as_bytes())?;
+        assert_eq!(27, Day09.part1(&full)?);
+        assert_eq!(27, Day09.part2(&full)?);
+
+        assert_eq!("disk map has no blocks", Day09.parse("".as_bytes()).unwrap_err().to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// Height of every position of the topographic map.
+    type Input = Grid<u8>;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        Grid::parse_with(reader, |c| c.to_digit(10).map(|height| height as u8))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1(map: &Grid<u8>) -> usize {
+    compute_trailhead_metrics(map, true)
+}
+
+fn part2(map: &Grid<u8>) -> usize {
+    compute_trailhead_metrics(map, false)
+}
+
+fn dfs(
+    map: &Grid<u8>,
+    visited: &mut HashSet<(Point, u8)>,
+    position: Point,
+    current_height: u8,
+    scores: &mut HashSet<Point>,
+) -> usize {
+    let mut distinct_trails = 0;
+
+    // If we reach height 9, it's a valid trail
+    if current_height == 9 {
+        scores.insert(position); // Add this position to reachable 9s
+        return 1;
+    }
+
+    // Possible moves (up, down, left, right)
+    for next in map.neighbours4(position) {
+        let height = map[next];
+        if height == current_height + 1 && !visited.contains(&(next, height)) {
+            // Mark this cell as visited for the current trail
+            visited.insert((next, height));
+
+            // Perform DFS
+            distinct_trails += dfs(map, visited, next, height, scores);
+
+            // Unmark the cell for other trails
+            visited.remove(&(next, height));
+        }
+    }
+
+    distinct_trails
+}
+
+fn compute_trailhead_metrics(map: &Grid<u8>, part_1: bool) -> usize {
+    let mut total_score = 0;
+    let mut total_rating = 0;
+
+    // Loop through all cells to find trailheads
+    for trailhead in map.find_all(&0) {
+        let mut visited = HashSet::new();
+        let mut scores = HashSet::new();
+        let trails_from_here = dfs(map, &mut visited, trailhead, 0, &mut scores);
+
+        total_score += scores.len(); // Add number of reachable 9s
+        total_rating += trails_from_here; // Add number of distinct trails
+    }
+    if part_1 {
+        total_score
+    } else {
+        total_rating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/10/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(36, Day10.part1(&Day10.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(81, Day10.part2(&Day10.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
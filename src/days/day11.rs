@@ -0,0 +1,100 @@
+use crate::parse::Line;
+use crate::{read_input, Answer, Solution};
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// The numbers engraved on the stones, left to right.
+    type Input = Vec<usize>;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        let text = read_input(reader)?;
+        let stones = Line::new(1, &text).parse_each(text.split_whitespace())?;
+        Ok(stones)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1(stones: &[usize]) -> usize {
+    count_stones_after_blinks(stones, 25)
+}
+
+fn count_stones_after_blinks(initial_stones: &[usize], blinks: usize) -> usize {
+    let mut stone_counts: HashMap<usize, usize> = HashMap::new();
+
+    // Initialize the map with the initial stones
+    for &stone in initial_stones {
+        *stone_counts.entry(stone).or_insert(0) += 1;
+    }
+
+    // Process each blink
+    for _ in 0..blinks {
+        let mut new_counts: HashMap<usize, usize> = HashMap::new();
+
+        for (&stone, &count) in stone_counts.iter() {
+            if stone == 0 {
+                // Rule 1: Replace `0` with `1`
+                *new_counts.entry(1).or_insert(0) += count;
+            } else if stone.to_string().len() % 2 == 0 {
+                // Rule 2: Split stones with even number of digits
+                let digits = stone.to_string();
+                let mid = digits.len() / 2;
+                let left: usize = digits[..mid].parse().unwrap();
+                let right: usize = digits[mid..].parse().unwrap();
+
+                *new_counts.entry(left).or_insert(0) += count;
+                *new_counts.entry(right).or_insert(0) += count;
+            } else {
+                // Rule 3: Multiply by 2024
+                let new_stone = stone * 2024;
+                *new_counts.entry(new_stone).or_insert(0) += count;
+            }
+        }
+
+        stone_counts = new_counts; // Update stone counts
+    }
+
+    // Total number of stones
+    stone_counts.values().sum()
+}
+
+fn part2(stones: &[usize]) -> usize {
+    count_stones_after_blinks(stones, 75)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/11/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(55312, Day11.part1(&Day11.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(65601038650482_i64, Day11.part2(&Day11.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
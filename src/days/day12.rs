@@ -0,0 +1,159 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// Plant type of every garden plot.
+    type Input = Grid<char>;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Garden Groups"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    calculate_total_price(grid)
+}
+
+fn flood_fill(grid: &Grid<char>, visited: &mut Grid<bool>, start: Point) -> (usize, usize, usize) {
+    let mut stack = vec![start];
+    let mut area = 0;
+    let mut perimeter = 0;
+    let plant_type = grid[start];
+
+    let mut region: HashSet<Point> = HashSet::new();
+
+    while let Some(point) = stack.pop() {
+        if visited[point] {
+            continue;
+        }
+        visited[point] = true;
+        area += 1;
+        region.insert(point);
+
+        // Check neighbors
+        for direction in Direction::ALL {
+            let next = point.step(direction);
+
+            if grid.get(next) == Some(&plant_type) {
+                if !visited[next] {
+                    stack.push(next);
+                }
+            } else {
+                // Neighboring cell belongs to another region or is out of bounds, contributes to perimeter
+                perimeter += 1;
+            }
+        }
+    }
+    let sides = find_sides(&region);
+
+    (area, perimeter, sides)
+}
+
+fn calculate_total_price(grid: &Grid<char>) -> usize {
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    let mut total_price = 0;
+
+    // Iterate through the grid
+    for point in grid.points() {
+        if !visited[point] {
+            let (area, perimeter, _) = flood_fill(grid, &mut visited, point);
+            total_price += area * perimeter;
+        }
+    }
+
+    total_price
+}
+
+fn calculate_total_price_with_sides(grid: &Grid<char>) -> usize {
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    let mut total_price = 0;
+
+    // Traverse the grid to find regions
+    for point in grid.points() {
+        if !visited[point] {
+            let (area, _, sides) = flood_fill(grid, &mut visited, point);
+            total_price += area * sides;
+        }
+    }
+
+    total_price
+}
+
+fn find_sides(region: &HashSet<Point>) -> usize {
+    let mut sides = 0;
+
+    for dir in Direction::ALL {
+        let mut found: HashSet<Point> = HashSet::new();
+        for &cell in region {
+            if !region.contains(&cell.step(dir)) && !found.contains(&cell) {
+                // this is a side, and we need to find adjacent's on the same side
+                found.insert(cell);
+                sides += 1;
+            } else {
+                continue;
+            }
+
+            // if top, go left, then right...
+            for lr_dir in [dir.turn_left(), dir.turn_right()] {
+                let mut cur = cell;
+                loop {
+                    cur = cur.step(lr_dir);
+
+                    if region.contains(&cur) && !region.contains(&cur.step(dir)) {
+                        // found adjacent
+                        found.insert(cur);
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    sides
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    calculate_total_price_with_sides(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/12/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(1930, Day12.part1(&Day12.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(1206, Day12.part2(&Day12.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
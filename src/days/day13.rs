@@ -0,0 +1,138 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+pub struct Day13;
+
+/// One claw machine: how far buttons A and B move the claw, and where the prize is.
+pub struct Machine {
+    pub a: (isize, isize),
+    pub b: (isize, isize),
+    pub prize: (isize, isize),
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_machines(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn parse_machines<R: BufRead>(reader: R) -> Result<Vec<Machine>> {
+    let mut machines = Vec::new();
+    let mut problem: Vec<(usize, String)> = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            problem.push((index + 1, line));
+        }
+        if problem.len() == 3 {
+            let [a, b, prize] = [0, 1, 2].map(|i| Line::new(problem[i].0, &problem[i].1));
+            machines.push(Machine {
+                a: parse_line(a, "+")?,
+                b: parse_line(b, "+")?,
+                prize: parse_line(prize, "=")?,
+            });
+            problem.clear();
+        }
+    }
+    ensure!(problem.is_empty(), "incomplete machine: {:?}", problem);
+
+    Ok(machines)
+}
+
+fn part1(machines: &[Machine]) -> usize {
+    machines.iter().map(|machine| solve(machine, false)).sum()
+}
+
+fn part2(machines: &[Machine]) -> usize {
+    machines.iter().map(|machine| solve(machine, true)).sum()
+}
+
+fn parse_line(line: Line, delimiter: &str) -> Result<(isize, isize)> {
+    let (_, coords) = line.split_once(":")?;
+    let (x, y) = line.split(coords, ",")?;
+    let coord = |part: &str| line.parse::<isize>(line.split(part, delimiter)?.1);
+    Ok((coord(x)?, coord(y)?))
+}
+
+fn solve(machine: &Machine, part_2: bool) -> usize {
+    let (xa, ya) = machine.a;
+    let (xb, yb) = machine.b;
+    let (mut xp, mut yp) = machine.prize;
+
+    // Adjust prize coordinates for part 2
+    if part_2 {
+        let adjustment = 10_000_000_000_000;
+        xp += adjustment;
+        yp += adjustment;
+    }
+
+    // Denominator for solving the linear equations
+    let denominator = xa * yb - ya * xb;
+    if denominator == 0 {
+        return 0; // Parallel lines, no intersection
+    }
+
+    // Calculate B
+    let b_numerator = yp * xa - ya * xp;
+    if b_numerator % denominator != 0 {
+        return 0; // B must be an integer
+    }
+    let b = b_numerator / denominator;
+    if b < 0 {
+        return 0; // B must be non-negative
+    }
+
+    // Calculate A
+    let a_numerator = xp - xb * b;
+    if a_numerator % xa != 0 {
+        return 0; // A must be an integer
+    }
+    let a = a_numerator / xa;
+    if a < 0 {
+        return 0; // A must be non-negative
+    }
+
+    // Return total cost
+    (a * 3 + b) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/13/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(480, Day13.part1(&Day13.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(875318608908_i64, Day13.part2(&Day13.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
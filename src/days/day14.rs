@@ -0,0 +1,184 @@
+use crate::geometry::Point;
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// Position and velocity of a robot, x being the column and y the row.
+pub type Robot = (Point, Point);
+
+#[derive(Deserialize)]
+pub struct Day14 {
+    /// Width and height of the robots' room.
+    pub size: (isize, isize),
+}
+
+impl Solution for Day14 {
+    /// Position and velocity of every robot.
+    type Input = Vec<Robot>;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input, self.size).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(self.tree_second(input)?.into())
+    }
+}
+
+fn parse(line: Line) -> Result<Robot> {
+    let vector = |part: &str| -> Result<Point> {
+        let (_, coords) = line.split(part, "=")?;
+        let (x, y) = line.split(coords, ",")?;
+        Ok(Point::new(line.parse(y)?, line.parse(x)?))
+    };
+
+    let (position, velocity) = line.split(line.text.trim(), " ")?;
+    Ok((vector(position)?, vector(velocity)?))
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Robot>> {
+    let mut robots = Vec::new();
+    for (index, text) in reader.lines().enumerate() {
+        let text = text?;
+        robots.push(parse(Line::new(index + 1, &text))?);
+    }
+    Ok(robots)
+}
+
+fn part1(robots: &[Robot], size: (isize, isize)) -> usize {
+    let rounds = 100;
+    let (mx, my) = (size.0 / 2, size.1 / 2);
+
+    // Initialize quadrant counts as an array
+    let mut quadrant_counts = [0; 4];
+
+    // Process each robot and count its quadrant
+    for &(position, velocity) in robots {
+        let moved = (position + velocity * rounds).wrap(size.1, size.0);
+        let (nx, ny) = (moved.col, moved.row);
+
+        if nx == mx || ny == my {
+            continue; // Skip points on the quadrant boundaries
+        }
+        // Determine the quadrant
+
+        let quadrant = match (nx < mx, ny < my) {
+            (true, true) => 0,   // Top-left
+            (true, false) => 1,  // Top-right
+            (false, true) => 2,  // Bottom-left
+            (false, false) => 3, // Bottom-right
+        };
+        // println!("{:?} {:?} {:?}", nx, ny, quadrant);
+        quadrant_counts[quadrant] += 1;
+    }
+
+    // Calculate the product of counts in all quadrants
+    quadrant_counts.iter().product::<usize>()
+}
+
+impl Day14 {
+    pub const PUZZLE: Day14 = Day14 { size: (101, 103) };
+
+    /// The first second at which the robots draw the Christmas tree; part 2's answer.
+    pub fn tree_second(&self, robots: &[Robot]) -> Result<isize> {
+        part2(robots, self.size)
+    }
+
+    /// Where every robot is after `seconds`.
+    pub fn positions(&self, robots: &[Robot], seconds: isize) -> Vec<Point> {
+        robots
+            .iter()
+            .map(|&(position, velocity)| (position + velocity * seconds).wrap(self.size.1, self.size.0))
+            .collect()
+    }
+
+    /// The room after `seconds`, a line per row with `*` on tiles that hold a robot.
+    pub fn render(&self, robots: &[Robot], seconds: isize) -> String {
+        let (width, height) = self.size;
+        let mut rows = vec![vec!['.'; width as usize]; height as usize];
+        for robot in self.positions(robots, seconds) {
+            rows[robot.row as usize][robot.col as usize] = '*';
+        }
+        rows.into_iter().map(|row| row.into_iter().chain(['\n']).collect::<String>()).collect()
+    }
+}
+
+// Did manually first by printing and inspecting the map  but then consulted reddit and follow the approaches suggested by others.
+fn part2(robots: &[Robot], size: (isize, isize)) -> Result<isize> {
+    let (grid_width, grid_height) = size;
+
+    for t in 0..grid_width * grid_height {
+        // Create a 2D grid to track positions and neighbors
+        let mut grid = vec![vec![0; grid_height as usize]; grid_width as usize];
+        let mut matching = HashSet::new();
+
+        for &(position, velocity) in robots {
+            // Calculate the next position
+            let moved = (position + velocity * t).wrap(grid_height, grid_width);
+            let (nx, ny) = (moved.col, moved.row);
+
+            // If position already has a robot, add to matching set
+            if grid[nx as usize][ny as usize] > 0 {
+                matching.insert((nx, ny));
+            }
+
+            // Mark the position and its neighbors
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let neighbor = Point::new(ny + dy, nx + dx).wrap(grid_height, grid_width);
+                    grid[neighbor.col as usize][neighbor.row as usize] += 1;
+                }
+            }
+        }
+
+        // The picture shows once enough robots are bunched together
+        if matching.len() > 190 {
+            return Ok(t);
+        }
+    }
+
+    bail!("the robots never bunch up into a picture")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: Day14 = Day14 { size: (11, 7) };
+    const TEST: &str = include_str!("../../examples/14/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(12, EXAMPLE.part1(&EXAMPLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_robot_positions() -> Result<()> {
+        let robots = EXAMPLE.parse("p=2,4 v=2,-3\n".as_bytes())?;
+
+        let rendered = EXAMPLE.render(&robots, 1);
+
+        assert_eq!(7, rendered.lines().count());
+        assert_eq!("....*......", rendered.lines().nth(1).unwrap());
+        assert_eq!(1, rendered.matches('*').count());
+        Ok(())
+    }
+
+    // The example has no Christmas tree, so part 2 has nothing to check against it.
+}
@@ -0,0 +1,237 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    /// The warehouse map and the robot's moves.
+    type Input = (Grid<char>, Vec<Direction>);
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Warehouse Woes"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn part1((map, moves): &(Grid<char>, Vec<Direction>)) -> usize {
+    let mut robot_pos = map.find(&'@').expect("the map has a robot");
+
+    // Add box positions to the set
+    let mut boxes: HashSet<Point> = map.find_all(&'O').collect();
+
+    // Process moves
+    for &dir in moves {
+        let delta = dir.delta();
+
+        let new_robot_pos = robot_pos + delta;
+
+        if map[new_robot_pos] == '#' {
+            continue; // Robot hits a wall, no movement
+        }
+
+        let mut current_pos = new_robot_pos;
+        let mut box_chain = Vec::new();
+
+        // Detect and collect the chain of boxes in the move direction
+        while boxes.contains(&current_pos) {
+            box_chain.push(current_pos);
+            current_pos += delta;
+        }
+
+        // Check if the chain of boxes can move
+        if map[current_pos] == '#' || boxes.contains(&current_pos) {
+            continue; // Blocked: neither the robot nor the boxes can move
+        }
+
+        // Move the chain of boxes
+        for &pos in box_chain.iter().rev() {
+            boxes.remove(&pos);
+            boxes.insert(pos + delta);
+        }
+
+        // Move the robot
+        robot_pos = new_robot_pos;
+    }
+
+    // Calculate GPS sum
+    boxes.iter().map(|p| 100 * p.row as usize + p.col as usize).sum()
+}
+
+fn parse_input<R: BufRead>(mut reader: R) -> Result<(Grid<char>, Vec<Direction>)> {
+    // The map ends at the blank line before the moves
+    let map = Grid::parse_with(&mut reader, |c| "#O.@".contains(c).then_some(c))?;
+    ensure!(map.find(&'@').is_some(), "the map has no robot");
+
+    let mut moves = Vec::new();
+    for (index, text) in reader.lines().enumerate() {
+        let text = text?;
+        // Line numbers continue after the map and the blank line
+        let line = Line::new(map.height() + index + 2, &text);
+        for (i, c) in text.trim_end().char_indices() {
+            let dir = Direction::from_char(c).ok_or_else(|| line.error(&text[i..i + c.len_utf8()], "expected a move"))?;
+            moves.push(dir);
+        }
+    }
+
+    Ok((map, moves))
+}
+
+// Doubles the width of the map for part 2, boxes becoming `[]`.
+fn widen(map: &Grid<char>) -> Grid<char> {
+    let mut wide = Grid::new(map.width() * 2, map.height(), '.');
+    for (point, &cell) in map.iter() {
+        let (left, right) = match cell {
+            '#' => ('#', '#'),
+            'O' => ('[', ']'),
+            '@' => ('@', '.'),
+            _ => ('.', '.'),
+        };
+        wide[Point::new(point.row, point.col * 2)] = left;
+        wide[Point::new(point.row, point.col * 2 + 1)] = right;
+    }
+    wide
+}
+
+fn part2((map, moves): &(Grid<char>, Vec<Direction>)) -> usize {
+    let mut map = widen(map);
+    let mut position = map.find(&'@').expect("the map has a robot");
+
+    for &dir in moves {
+        let delta = dir.delta();
+
+        // Calculate the next position of the robot.
+        let next = position + delta;
+
+        // Case 1: Move the robot to an empty space.
+        if map[next] == '.' {
+            map[position] = '.'; // Clear the robot's previous position.
+            map[next] = '@'; // Move the robot to the new position.
+            position = next;
+            continue;
+        }
+
+        // Case 2: Handle movement when encountering boxes.
+        if map[next] == '[' || map[next] == ']' {
+            let mut boxes = vec![next];
+
+            // Add the other side of the box to the list.
+            if map[next] == '[' {
+                boxes.push(next.step(Direction::Right));
+            } else {
+                boxes.push(next.step(Direction::Left));
+            }
+
+            // Handle vertical movement with complex box arrangements, horizontal movement with
+            // straight-line box arrangements.
+            let blocked = if dir.is_vertical() {
+                handle_vertical_boxes(&mut boxes, delta, &map)
+            } else {
+                handle_horizontal_boxes(&mut boxes, delta, &map)
+            };
+
+            // If not blocked, move all boxes and the robot.
+            if !blocked {
+                move_boxes_and_robot(&mut map, &boxes, delta);
+                map[position] = '.'; // Clear the robot's previous position.
+                map[next] = '@'; // Move the robot to the new position.
+                position = next;
+            }
+        }
+    }
+
+    map.find_all(&'[').map(|p| 100 * p.row as usize + p.col as usize).sum()
+}
+
+/// Handles vertical movement with boxes and checks for blocked paths.
+fn handle_vertical_boxes(boxes: &mut Vec<Point>, delta: Point, map: &Grid<char>) -> bool {
+    let mut current = boxes.clone();
+
+    while current.len() > 1 {
+        let mut next = Vec::new();
+
+        for &point in &current {
+            let path = point + delta;
+
+            if map[path] == '#' {
+                return true; // Blocked by a wall.
+            }
+
+            if (map[path] == '[' || map[path] == ']') && !next.contains(&path) {
+                boxes.push(path);
+                next.push(path);
+
+                let other = if map[path] == '[' { path.step(Direction::Right) } else { path.step(Direction::Left) };
+                boxes.push(other);
+                next.push(other);
+            }
+        }
+
+        current = next;
+    }
+
+    false
+}
+
+/// Handles horizontal movement with boxes and checks for blocked paths.
+fn handle_horizontal_boxes(boxes: &mut Vec<Point>, delta: Point, map: &Grid<char>) -> bool {
+    let mut path = boxes[0] + delta;
+
+    while map[path] == '[' || map[path] == ']' {
+        if !boxes.contains(&path) {
+            boxes.push(path);
+        }
+
+        path += delta;
+    }
+
+    // Check if the path is blocked by something other than an empty space.
+    map[path] != '.'
+}
+
+/// Moves all boxes in the list and updates the map.
+fn move_boxes_and_robot(map: &mut Grid<char>, boxes: &[Point], delta: Point) {
+    for &point in boxes.iter().rev() {
+        map[point + delta] = map[point]; // Move the box to its new position.
+        map[point] = '.'; // Clear the box's old position.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/15/small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(908, Day15.part1(&Day15.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(618, Day15.part2(&Day15.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::search::{dijkstra, Search};
+use crate::{Answer, Solution};
+use anyhow::*;
+use image::{Rgb, RgbImage};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::BufRead;
+
+/// Scoring of the reindeer's moves; [`Day16::PUZZLE`] is the puzzle's own, and any field a
+/// parameter table leaves out keeps its value.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default)]
+pub struct Day16 {
+    /// Score of a step forward.
+    pub step_cost: usize,
+    /// Score of a quarter turn.
+    pub turn_cost: usize,
+    /// Whether the reindeer may turn around in a single move, scoring one turn.
+    pub u_turns: bool,
+    /// Facing on the start tile.
+    pub start: Direction,
+}
+
+impl Day16 {
+    pub const PUZZLE: Day16 = Day16 { step_cost: 1, turn_cost: 1000, u_turns: false, start: Direction::Right };
+}
+
+impl Default for Day16 {
+    fn default() -> Day16 {
+        Day16::PUZZLE
+    }
+}
+
+/// The maze with the reindeer's start and end tiles.
+pub struct Maze {
+    pub map: Grid<char>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Reindeer Maze"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(self, input)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(self, input).into())
+    }
+}
+
+/// Side of a tile in [`Day16::render_image`], in pixels.
+pub const TILE_PIXELS: u32 = 4;
+
+// The reindeer's position and facing.
+type State = (Point, Direction);
+
+/// One move of the reindeer along a route.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Forward,
+    TurnLeft,
+    TurnRight,
+    TurnAround,
+}
+
+/// Drawn as `F`, `L`, `R` and `U`, so a route reads as one word.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Move::Forward => 'F',
+            Move::TurnLeft => 'L',
+            Move::TurnRight => 'R',
+            Move::TurnAround => 'U',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Day16 {
+    // Cheapest ways from the start tile to every state until `is_goal` ones.
+    fn search(&self, maze: &Maze, is_goal: impl FnMut(&State) -> bool) -> Search<State> {
+        let neighbours = |&(position, direction): &State| {
+            let forward = position.step(direction);
+            // Move in the same direction, or rotate to either side or, if allowed, around
+            let open = maze.map.get(forward).is_some_and(|&tile| tile != '#');
+            let step = open.then_some(((forward, direction), self.step_cost));
+            let around = self.u_turns.then_some(direction.reverse());
+            let turns = [direction.turn_right(), direction.turn_left()].into_iter().chain(around);
+            step.into_iter().chain(turns.map(move |turned| ((position, turned), self.turn_cost)))
+        };
+
+        dijkstra([(maze.start, self.start)], neighbours, is_goal)
+    }
+
+    fn search_end(&self, maze: &Maze) -> Search<State> {
+        self.search(maze, |&(position, _)| position == maze.end)
+    }
+
+    /// Every route with the lowest score, as the moves the reindeer makes from the start tile.
+    pub fn optimal_routes(&self, maze: &Maze) -> Vec<Vec<Move>> {
+        let search = self.search_end(maze);
+
+        let mut routes = Vec::new();
+        for goal in &search.goals {
+            for path in search.paths_to(goal) {
+                let route = path
+                    .windows(2)
+                    .map(|pair| match (pair[0], pair[1]) {
+                        ((from, _), (to, _)) if from != to => Move::Forward,
+                        ((_, facing), (_, turned)) if turned == facing.turn_left() => Move::TurnLeft,
+                        ((_, facing), (_, turned)) if turned == facing.turn_right() => Move::TurnRight,
+                        _ => Move::TurnAround,
+                    })
+                    .collect();
+                routes.push(route);
+            }
+        }
+        routes
+    }
+
+    /// Lowest score reaching every tile from the start, whichever way the reindeer then faces;
+    /// `None` for walls and tiles it cannot reach.
+    pub fn tile_costs(&self, maze: &Maze) -> Grid<Option<usize>> {
+        let search = self.search(maze, |_| false);
+
+        let mut costs = Grid::new(maze.map.width(), maze.map.height(), None);
+        for (&(position, _), &cost) in &search.distances {
+            let best = &mut costs[position];
+            *best = Some(best.map_or(cost, |best: usize| best.min(cost)));
+        }
+        costs
+    }
+
+    /// Tiles on at least one route with the lowest score.
+    pub fn best_tiles(&self, maze: &Maze) -> HashSet<Point> {
+        let search = self.search_end(maze);
+        let states = search.on_shortest_paths(search.goals.clone());
+        states.into_iter().map(|(position, _)| position).collect()
+    }
+
+    /// The maze as in the puzzle input, with the floor of the best routes drawn as `O`.
+    pub fn render(&self, maze: &Maze) -> String {
+        let mut map = maze.map.clone();
+        for tile in self.best_tiles(maze) {
+            if map[tile] == '.' {
+                map[tile] = 'O';
+            }
+        }
+        map.to_string()
+    }
+
+    /// The maze as an image, [`TILE_PIXELS`] square per tile: walls dark, the best routes red, the
+    /// start green and the end blue.
+    pub fn render_image(&self, maze: &Maze) -> RgbImage {
+        let best = self.best_tiles(maze);
+
+        let (width, height) = (maze.map.width() as u32, maze.map.height() as u32);
+        RgbImage::from_fn(width * TILE_PIXELS, height * TILE_PIXELS, |x, y| {
+            let point = Point::new((y / TILE_PIXELS) as isize, (x / TILE_PIXELS) as isize);
+            match maze.map[point] {
+                '#' => Rgb([40, 40, 40]),
+                'S' => Rgb([40, 160, 60]),
+                'E' => Rgb([40, 90, 200]),
+                _ if best.contains(&point) => Rgb([210, 50, 50]),
+                _ => Rgb([235, 235, 235]),
+            }
+        })
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Maze> {
+    let map = Grid::parse(reader)?;
+
+    // Find start (S) and end (E) positions
+    let start = map.find(&'S').context("the maze has no start tile")?;
+    let end = map.find(&'E').context("the maze has no end tile")?;
+
+    Ok(Maze { map, start, end })
+}
+
+fn part1(rules: &Day16, maze: &Maze) -> Result<usize> {
+    rules.search_end(maze).cost().context("the end tile cannot be reached")
+}
+
+fn part2(rules: &Day16, maze: &Maze) -> usize {
+    // Count the tiles of every best path, whichever way the reindeer faces on them
+    rules.best_tiles(maze).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: Day16 = Day16::PUZZLE;
+    const TEST: &str = include_str!("../../examples/16/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(11048, PUZZLE.part1(&PUZZLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(64, PUZZLE.part2(&PUZZLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn routes_explain_the_score() -> Result<()> {
+        let maze = PUZZLE.parse(TEST.as_bytes())?;
+
+        let routes = PUZZLE.optimal_routes(&maze);
+        assert!(routes.len() > 1);
+        for route in &routes {
+            let score: usize = route.iter().map(|&step| if step == Move::Forward { 1 } else { 1000 }).sum();
+            assert_eq!(11048, score);
+        }
+
+        assert_eq!(Some(11048), PUZZLE.tile_costs(&maze)[maze.end]);
+        assert_eq!(None, PUZZLE.tile_costs(&maze)[Point::new(0, 0)]);
+        assert_eq!(62, PUZZLE.render(&maze).matches('O').count()); // Every best tile but the start and end
+        assert_eq!(maze.map.width() as u32 * TILE_PIXELS, PUZZLE.render_image(&maze).width());
+        Ok(())
+    }
+
+    #[test]
+    fn variants_change_the_scoring() -> Result<()> {
+        // Facing away from the end, so the reindeer has to turn around first
+        let maze = PUZZLE.parse("#####\n#S.E#\n#####\n".as_bytes())?;
+        let facing_west = Day16 { start: Direction::Left, ..PUZZLE };
+
+        assert_eq!(2002, part1(&facing_west, &maze)?);
+        let u_turns = Day16 { u_turns: true, ..facing_west };
+        assert_eq!(1002, part1(&u_turns, &maze)?);
+        assert_eq!(vec![vec![Move::TurnAround, Move::Forward, Move::Forward]], u_turns.optimal_routes(&maze));
+        assert_eq!(2010, part1(&Day16 { step_cost: 5, ..facing_west }, &maze)?);
+        assert_eq!(20, part1(&Day16 { step_cost: 10, ..PUZZLE }, &maze)?);
+
+        // Free moves must not send the search round in circles
+        let free_turns = Day16 { turn_cost: 0, ..PUZZLE };
+        assert_eq!((2, 3), (part1(&free_turns, &maze)?, part2(&free_turns, &maze)));
+        assert_eq!(vec![vec![Move::Forward, Move::Forward]], free_turns.optimal_routes(&maze));
+        let free_steps = Day16 { step_cost: 0, ..facing_west };
+        assert_eq!(2000, part1(&free_steps, &maze)?);
+        assert_eq!(2, free_steps.optimal_routes(&maze).len());
+
+        // Parameter tables only name what differs from the puzzle
+        let table: Day16 = toml::from_str("u_turns = true\nstart = \"west\"")?;
+        assert_eq!(1002, part1(&table, &maze)?);
+        Ok(())
+    }
+}
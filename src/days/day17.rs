@@ -0,0 +1,224 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    /// Registers A, B and C, and the program.
+    type Input = (i64, i64, i64, Vec<i64>);
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Chronospatial Computer"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(i64, i64, i64, Vec<i64>)> {
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+
+    // Every line is `<name>: <value>`, with a blank line between the registers and the program.
+    let mut values = Vec::new();
+    for (index, text) in lines.iter().enumerate() {
+        if !text.trim().is_empty() {
+            let line = Line::new(index + 1, text);
+            values.push((line, line.split_once(":")?.1));
+        }
+    }
+    let [(a_line, a), (b_line, b), (c_line, c), (program_line, program)] = values[..] else {
+        bail!("expected three registers and a program, got {} lines", values.len());
+    };
+
+    // Opcodes and operands are 3-bit values; operand 7 is reserved for combo instructions.
+    let fields: Vec<&str> = program.split(',').map(str::trim).collect();
+    if !fields.len().is_multiple_of(2) {
+        bail!(program_line.error(program.trim(), "expected opcode and operand pairs"));
+    }
+    let program: Vec<i64> = program_line.parse_each(fields.iter().copied())?;
+    for (index, &value) in program.iter().enumerate() {
+        if !(0..=7).contains(&value) {
+            bail!(program_line.error(fields[index], "expected a value from 0 to 7"));
+        }
+        if index % 2 == 1 && value == 7 && uses_combo(program[index - 1]) {
+            bail!(program_line.error(fields[index], "combo operand 7 is reserved"));
+        }
+    }
+
+    Ok((a_line.parse(a)?, b_line.parse(b)?, c_line.parse(c)?, program))
+}
+
+fn uses_combo(command: i64) -> bool {
+    matches!(command, 0 | 2 | 5 | 6 | 7)
+}
+
+fn combo(operand: i64, a: i64, b: i64, c: i64) -> i64 {
+    match operand {
+        4 => a,
+        5 => b,
+        6 => c,
+        // `parse_input` rejects combo operand 7, so the rest are literals.
+        _ => operand,
+    }
+}
+
+/// `numerator / 2^exponent`, which is 0 once the divisor no longer fits in an `i64`.
+fn divide(numerator: i64, exponent: i64) -> i64 {
+    u32::try_from(exponent).ok().and_then(|exponent| 2_i64.checked_pow(exponent)).map_or(0, |d| numerator / d)
+}
+
+fn simulate(code: &[i64], mut a: i64, mut b: i64, mut c: i64, output: &mut Vec<i64>) {
+    let mut pc: usize = 0;
+
+    // The machine halts once an instruction would read past the end of the program.
+    while pc + 1 < code.len() {
+        let command = code[pc];
+        let operand = code[pc + 1];
+
+        let value = uses_combo(command).then(|| combo(operand, a, b, c));
+
+        match command {
+            0 => {
+                // adv
+                a = divide(a, value.unwrap_or(0));
+            }
+            1 => {
+                // bxl
+                b ^= operand;
+            }
+            2 => {
+                // bst
+                b = value.unwrap_or(0) % 8;
+            }
+            3 => {
+                // jnz
+                if a != 0 {
+                    pc = operand as usize;
+                    continue;
+                }
+            }
+            4 => {
+                // bxc
+                b ^= c;
+            }
+            5 => {
+                // out
+                output.push(value.unwrap_or(0) % 8);
+            }
+            6 => {
+                // bdv
+                b = divide(a, value.unwrap_or(0));
+            }
+            _ => {
+                // cdv; `parse_input` only lets opcodes 0 to 7 through
+                c = divide(a, value.unwrap_or(0));
+            }
+        }
+
+        pc += 2;
+    }
+}
+
+fn part1(&(a, b, c, ref program): &(i64, i64, i64, Vec<i64>)) -> String {
+    // Initialize output vector
+    let mut output = Vec::new();
+
+    // Simulate the program
+    simulate(program, a, b, c, &mut output);
+
+    // Join the output values
+    output
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn part2(&(_, b, c, ref program): &(i64, i64, i64, Vec<i64>)) -> Result<i64> {
+    get_new_a(b, c, program).context("no value of register A makes the program output itself")
+}
+
+fn get_new_a(b: i64, c: i64, program: &[i64]) -> Option<i64> {
+    let mut q: VecDeque<i64> = VecDeque::new();
+    q.push_back(0);
+    let program_len = program.len();
+
+    for i in 0..program_len {
+        // Calculate the expected output slice once
+        let expected = &program[program_len.saturating_sub(i + 1)..];
+
+        for _ in 0..q.len() {
+            if let Some(v) = q.pop_front() {
+                for k in 0..8 {
+                    let a = 8 * v + k;
+
+                    // Simulate the output only when necessary
+                    let mut output = Vec::with_capacity(expected.len());
+                    simulate(program, a, b, c, &mut output);
+
+                    // Check only the portion of `output` that matches `expected`
+                    if output.len() >= expected.len()
+                        && &output[output.len() - expected.len()..] == expected
+                    {
+                        q.push_back(a);
+                    }
+                }
+            }
+        }
+
+        // If there are no candidates left, stop early
+        if q.is_empty() {
+            break;
+        }
+    }
+    // Return the smallest valid `a` found, or `None` if no valid `a` exists
+    q.iter().min().cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_PART1: &str = include_str!("../../examples/17/part1.txt");
+    const TEST: &str = include_str!("../../examples/17/part2.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", Day17.part1(&Day17.parse(TEST_PART1.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(117440, Day17.part2(&Day17.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_programs() {
+        let error = |program: &str| {
+            let input = format!("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program);
+            Day17.parse(input.as_bytes()).unwrap_err().root_cause().to_string()
+        };
+
+        assert_eq!("line 5, column 10: '0,1,5,4,3': expected opcode and operand pairs", error("0,1,5,4,3"));
+        assert_eq!("line 5, column 12: '7': combo operand 7 is reserved", error("0,7,5,4"));
+        assert_eq!("line 5, column 14: '8': expected a value from 0 to 7", error("1,7,8,4"));
+    }
+}
@@ -0,0 +1,221 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::parse::Line;
+use crate::search::{astar, Search};
+use crate::union_find::UnionFind;
+use crate::{Answer, Solution};
+use anyhow::*;
+use serde::Deserialize;
+use std::io::BufRead;
+
+#[derive(Deserialize)]
+pub struct Day18 {
+    /// Bottom-right corner of the memory space, as (x, y).
+    pub dest: (usize, usize),
+    /// Number of bytes that have fallen before the first measurement.
+    pub limit: usize,
+    /// How part 2 finds the first byte cutting the exit off; union-find unless given.
+    #[serde(default)]
+    pub mode: Mode,
+}
+
+impl Day18 {
+    pub const PUZZLE: Day18 = Day18 { dest: (70, 70), limit: 1024, mode: Mode::UnionFind };
+}
+
+/// Ways of finding the first byte that cuts the exit off.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+    /// Lets the bytes fall one by one, searching again whenever one lands on the current path.
+    Replan,
+    /// Binary search over the number of fallen bytes, searching once per probe.
+    BinarySearch,
+    /// Clears the bytes again from the last one, joining open cells until the exit connects;
+    /// near-linear in the size of the memory space.
+    #[default]
+    UnionFind,
+}
+
+impl Solution for Day18 {
+    /// Where each byte falls, in falling order.
+    type Input = Vec<Point>;
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input, self.dest, self.limit)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input, self.dest, self.limit, self.mode)?.into())
+    }
+}
+
+// The memory space from the origin to `dest`, with the first `count` bytes marked as corrupted.
+fn memory(points: &[Point], dest: (usize, usize), count: usize) -> (Grid<bool>, Point) {
+    let mut corrupted = Grid::new(dest.0 + 1, dest.1 + 1, false);
+    for &point in &points[..count.min(points.len())] {
+        if let Some(cell) = corrupted.get_mut(point) {
+            *cell = true;
+        }
+    }
+    (corrupted, Point::new(dest.1 as isize, dest.0 as isize))
+}
+
+// Shortest way through the memory space from the top-left corner to `dst`, around corrupted bytes.
+fn shortest_path(corrupted: &Grid<bool>, dst: Point) -> Search<Point> {
+    let neighbours =
+        |&point: &Point| corrupted.neighbours4(point).filter(|&next| !corrupted[next]).map(|next| (next, 1));
+    astar([Point::new(0, 0)], neighbours, |&point| point.manhattan(dst), |&point| point == dst)
+}
+
+fn part1(points: &[Point], dest: (usize, usize), limit: usize) -> Result<usize> {
+    let (corrupted, dst) = memory(points, dest, limit);
+    shortest_path(&corrupted, dst).cost().context("the exit cannot be reached")
+}
+
+fn process_astar(points: &[Point], dest: (usize, usize), limit: usize) -> Option<Point> {
+    let (mut corrupted, dst) = memory(points, dest, limit);
+    let mut path = vec![];
+
+    for &point in points.iter().skip(limit) {
+        if let Some(cell) = corrupted.get_mut(point) {
+            *cell = true;
+        }
+        // Only a byte falling on the current path can cut the exit off
+        if path.is_empty() || path.contains(&point) {
+            match shortest_path(&corrupted, dst).path() {
+                Some(found) => path = found,
+                None => return Some(point),
+            }
+        }
+    }
+    None
+}
+
+fn binary_search(points: &[Point], dest: (usize, usize), limit: usize) -> Option<Point> {
+    // Reachability only gets lost as bytes fall, so the counts still reachable come first
+    let counts: Vec<usize> = (limit.min(points.len()) + 1..=points.len()).collect();
+    let reachable = counts.partition_point(|&count| {
+        let (corrupted, dst) = memory(points, dest, count);
+        shortest_path(&corrupted, dst).cost().is_some()
+    });
+    counts.get(reachable).map(|&count| points[count - 1])
+}
+
+fn union_find(points: &[Point], dest: (usize, usize), limit: usize) -> Option<Point> {
+    let (mut corrupted, dst) = memory(points, dest, points.len());
+    let width = corrupted.width();
+    let index = move |point: Point| point.row as usize * width + point.col as usize;
+    let mut sets = UnionFind::new(corrupted.width() * corrupted.height());
+
+    // Which byte corrupts each cell first; only clearing that one opens the cell
+    let mut first = Grid::new(corrupted.width(), corrupted.height(), usize::MAX);
+    for (i, &point) in points.iter().enumerate().rev() {
+        if let Some(cell) = first.get_mut(point) {
+            *cell = i;
+        }
+    }
+
+    let open = |sets: &mut UnionFind, corrupted: &Grid<bool>, point: Point| {
+        for next in corrupted.neighbours4(point).filter(|&next| !corrupted[next]) {
+            sets.union(index(point), index(next));
+        }
+    };
+    for point in corrupted.points().filter(|&point| !corrupted[point]) {
+        open(&mut sets, &corrupted, point);
+    }
+    let origin = Point::new(0, 0);
+    if sets.connected(index(origin), index(dst)) && !corrupted[origin] && !corrupted[dst] {
+        return None; // Even every byte together leaves a way out
+    }
+
+    for (i, &point) in points.iter().enumerate().skip(limit).rev() {
+        if first.get(point) != Some(&i) {
+            continue;
+        }
+        corrupted[point] = false;
+        open(&mut sets, &corrupted, point);
+        if !corrupted[origin] && !corrupted[dst] && sets.connected(index(origin), index(dst)) {
+            return Some(point);
+        }
+    }
+    None
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Point>> {
+    let mut points = Vec::new();
+    for (index, text) in reader.lines().enumerate() {
+        let text = text?;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let line = Line::new(index + 1, &text);
+        let (x, y) = line.split_once(",")?;
+        points.push(Point::new(line.parse(y)?, line.parse(x)?));
+    }
+    Ok(points)
+}
+
+fn part2(points: &[Point], dest: (usize, usize), limit: usize, mode: Mode) -> Result<String> {
+    // Every mode only looks at the bytes after the first `limit`
+    let (corrupted, dst) = memory(points, dest, limit);
+    ensure!(shortest_path(&corrupted, dst).cost().is_some(), "the first {} bytes already cut the exit off", limit);
+
+    let point = match mode {
+        Mode::Replan => process_astar(points, dest, limit),
+        Mode::BinarySearch => binary_search(points, dest, limit),
+        Mode::UnionFind => union_find(points, dest, limit),
+    };
+    let point = point.context("no byte cuts the exit off")?;
+    Ok(format!("{},{}", point.col, point.row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: Day18 = Day18 { dest: (6, 6), limit: 12, mode: Mode::UnionFind };
+    const TEST: &str = include_str!("../../examples/18/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(22, EXAMPLE.part1(&EXAMPLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!("6,1", EXAMPLE.part2(&EXAMPLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn modes_agree() -> Result<()> {
+        let points = EXAMPLE.parse(TEST.as_bytes())?;
+        for mode in [Mode::Replan, Mode::BinarySearch, Mode::UnionFind] {
+            assert_eq!("6,1", part2(&points, EXAMPLE.dest, EXAMPLE.limit, mode)?);
+        }
+
+        // A wall of bytes that only closes with its last one
+        let wall: Vec<Point> = (0..3).map(|row| Point::new(row, 1)).collect();
+        for mode in [Mode::Replan, Mode::BinarySearch, Mode::UnionFind] {
+            assert_eq!("1,2", part2(&wall, (2, 2), 0, mode)?);
+            assert!(part2(&wall[..2], (2, 2), 0, mode).is_err());
+            let error = part2(&wall, (2, 2), 3, mode).unwrap_err();
+            assert_eq!("the first 3 bytes already cut the exit off", error.to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    /// The available towel patterns and the designs to make.
+    type Input = (Vec<String>, Vec<String>);
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Linen Layout"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn get_designs(pattern: &str, towel_list: &[String], cache: &mut HashMap<String, bool>) -> bool {
+    if let Some(&cached_result) = cache.get(pattern) {
+        return cached_result;
+    }
+
+    if pattern.is_empty() {
+        cache.insert(pattern.to_string(), true);
+        return true;
+    }
+
+    for towel in towel_list {
+        // eprintln!("Checking: design = '{}', option = '{:?}'", pattern, towel);
+        if pattern.starts_with(towel) && get_designs(&pattern[towel.len()..], towel_list, cache) {
+            cache.insert(pattern.to_string(), true);
+            return true;
+        }
+    }
+
+    cache.insert(pattern.to_string(), false);
+    false
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Vec<String>, Vec<String>)> {
+    let mut lines = reader.lines();
+
+    let binding = lines.next().context("missing towel patterns")??;
+    let towel_list: Vec<String> = binding
+        .split(',')
+        .map(|s| s.trim().trim_matches('"').to_string())
+        .collect();
+    lines.next();
+
+    // Read desired patterns
+    let desired_pattern: Vec<String> = lines.collect::<Result<_, _>>()?;
+    Ok((towel_list, desired_pattern))
+}
+
+fn part1((towel_list, desired_pattern): &(Vec<String>, Vec<String>)) -> usize {
+    let mut output = 0;
+    let mut cache = HashMap::new();
+
+    for pattern in desired_pattern {
+        if get_designs(pattern, towel_list, &mut cache) {
+            output += 1;
+        }
+    }
+    output
+}
+
+fn get_all_possible_designs(
+    pattern: &str,
+    towel_list: &[String],
+    cache: &mut HashMap<String, i64>,
+) -> i64 {
+    if let Some(&cached_result) = cache.get(pattern) {
+        return cached_result;
+    }
+
+    if pattern.is_empty() {
+        cache.insert(pattern.to_string(), 1);
+        return 1;
+    }
+    let mut count = 0;
+
+    for towel in towel_list {
+        if pattern.starts_with(towel) {
+            count += get_all_possible_designs(&pattern[towel.len()..], towel_list, cache);
+        }
+    }
+
+    cache.insert(pattern.to_string(), count);
+    count
+}
+
+fn part2((towel_list, desired_pattern): &(Vec<String>, Vec<String>)) -> i64 {
+    let mut output: i64 = 0;
+    let mut cache = HashMap::new();
+
+    for pattern in desired_pattern {
+        output += get_all_possible_designs(pattern, towel_list, &mut cache);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/19/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(6, Day19.part1(&Day19.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(16, Day19.part2(&Day19.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
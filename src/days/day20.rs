@@ -0,0 +1,134 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::search::bfs;
+use crate::{Answer, Solution};
+use anyhow::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+#[derive(Deserialize)]
+pub struct Day20 {
+    /// Fewest picoseconds a cheat must save to be counted.
+    pub min_saving: usize,
+}
+
+impl Day20 {
+    pub const PUZZLE: Day20 = Day20 { min_saving: 100 };
+}
+
+/// The racetrack map with its start and end positions.
+pub struct Racetrack {
+    pub map: Grid<char>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Solution for Day20 {
+    type Input = Racetrack;
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Race Condition"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input, self.min_saving).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input, self.min_saving).into())
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Racetrack> {
+    let map = Grid::parse(reader)?;
+
+    // Find the start ('S') and end ('E') positions
+    let start = map.find(&'S').context("the racetrack has no start position")?;
+    let end = map.find(&'E').context("the racetrack has no end position")?;
+
+    Ok(Racetrack { map, start, end })
+}
+
+// Distance of every track position from the end.
+fn distances(track: &Racetrack) -> HashMap<Point, usize> {
+    let map = &track.map;
+    let neighbours = |&point: &Point| map.neighbours4(point).filter(|&next| map[next] != '#');
+    bfs([track.end], neighbours, |_| false).distances
+}
+
+fn part1(track: &Racetrack, min_saving: usize) -> usize {
+    let dist = distances(track);
+
+    let mut cheat_count = 0;
+
+    // Collect and sort keys by their values in descending order
+    let mut keys: Vec<(&Point, &usize)> = dist.iter().collect();
+    keys.sort_by_key(|&(_, &v)| std::cmp::Reverse(v)); // Sort by distance in descending order
+
+    for (&k, &v) in keys {
+        for direction in Direction::ALL {
+            if let Some(&nv) = dist.get(&(k + direction.delta() * 2)) {
+                if (v as isize - nv as isize - 2) >= min_saving as isize {
+                    cheat_count += 1;
+                }
+            }
+        }
+    }
+
+    cheat_count
+}
+
+fn part2(track: &Racetrack, min_saving: usize) -> usize {
+    let dist = distances(track);
+
+    let mut cheat_count = 0;
+
+    // Collect keys and sort them in descending order based on their values
+    let mut keys: Vec<(&Point, &usize)> = dist.iter().collect();
+    keys.sort_by_key(|&(_, &v)| std::cmp::Reverse(v)); // Sort by distance values in descending order
+
+    for (&k, &v) in keys {
+        for (&k2, &v2) in dist.iter() {
+            // Compute Manhattan distance and filter candidates early
+            let d = k.manhattan(k2);
+            if d > 20 {
+                continue; // Skip pairs that are too far
+            }
+
+            if v2 as isize - v as isize - d as isize >= min_saving as isize {
+                cheat_count += 1;
+            }
+        }
+    }
+
+    cheat_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: Day20 = Day20 { min_saving: 50 };
+    const TEST: &str = include_str!("../../examples/20/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(1, EXAMPLE.part1(&EXAMPLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(285, EXAMPLE.part2(&EXAMPLE.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
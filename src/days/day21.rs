@@ -0,0 +1,241 @@
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::geometry::Direction;
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::sync::Mutex;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    /// The door codes to type, with their numeric part.
+    type Input = Vec<(String, usize)>;
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Keypad Conundrum"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+// Tables for coordinates
+fn number_table() -> HashMap<char, (isize, isize)> {
+    [
+        ('7', (0, 0)),
+        ('8', (0, 1)),
+        ('9', (0, 2)),
+        ('4', (1, 0)),
+        ('5', (1, 1)),
+        ('6', (1, 2)),
+        ('1', (2, 0)),
+        ('2', (2, 1)),
+        ('3', (2, 2)),
+        ('0', (3, 1)),
+        ('A', (3, 2)),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+fn small_table() -> HashMap<char, (isize, isize)> {
+    [
+        ('^', (0, 1)),
+        ('A', (0, 2)),
+        ('<', (1, 0)),
+        ('v', (1, 1)),
+        ('>', (1, 2)),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+struct Solver {
+    max_depth: usize,
+}
+
+impl Solver {
+    fn new(max_depth: usize) -> Self {
+        Self { max_depth }
+    }
+
+    fn simulate(
+        &self,
+        line: &str,
+        mut py: isize,
+        mut px: isize,
+        gapy: isize,
+        gapx: isize,
+    ) -> bool {
+        for c in line.chars() {
+            if c == 'A' {
+                continue;
+            }
+            let delta = Direction::from_char(c).expect("a move key").delta();
+            py += delta.row;
+            px += delta.col;
+            if py == gapy && px == gapx {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn small_step(
+        &self,
+        py: isize,
+        px: isize,
+        ny: isize,
+        nx: isize,
+        depth: isize,
+        memo: &mut HashMap<(isize, isize, isize, isize, isize), usize>,
+        best_so_far: usize,
+    ) -> usize {
+        if let Some(&cached) = memo.get(&(py, px, ny, nx, depth)) {
+            return cached;
+        }
+
+        let dy = ny - py;
+        let dx = nx - px;
+        let mut best = usize::MAX;
+
+        for perm in (0..4).permutations(4) {
+            let mut ans = String::new();
+            for &p in &perm {
+                match p {
+                    0 if dx > 0 => ans += &">".repeat(dx.unsigned_abs()),
+                    1 if dy > 0 => ans += &"v".repeat(dy.unsigned_abs()),
+                    2 if dx < 0 => ans += &"<".repeat(dx.unsigned_abs()),
+                    3 if dy < 0 => ans += &"^".repeat(dy.unsigned_abs()),
+                    _ => {}
+                }
+            }
+            ans.push('A');
+            let (gapy, gapx) = if depth >= 0 { (0, 0) } else { (3, 0) };
+            if self.simulate(&ans, py, px, gapy, gapx) {
+                let candidate =
+                    self.walk_line(&ans, depth + 1, memo, best_so_far.min(best));
+                if candidate < best_so_far {
+                    best = best.min(candidate);
+                }
+            }
+        }
+
+        memo.insert((py, px, ny, nx, depth), best);
+        best
+    }
+
+    fn walk_line(
+        &self,
+        line: &str,
+        depth: isize,
+        memo: &mut HashMap<(isize, isize, isize, isize, isize), usize>,
+        best_so_far: usize,
+    ) -> usize {
+        if depth == self.max_depth as isize {
+            return line.len();
+        }
+
+        let table = if depth >= 0 {
+            small_table()
+        } else {
+            number_table()
+        };
+
+        let mut py = table[&'A'].0;
+        let mut px = table[&'A'].1;
+        let mut size = 0;
+
+        for c in line.chars() {
+            let (ny, nx) = table[&c];
+            size += self.small_step(py, px, ny, nx, depth, memo, best_so_far);
+            py = ny;
+            px = nx;
+        }
+
+        size
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<(String, usize)>> {
+    let mut codes = Vec::new();
+    for (index, code) in reader.lines().enumerate() {
+        let code = code?;
+        let line = Line::new(index + 1, &code);
+        if let Some((bad, key)) = code.char_indices().find(|&(_, c)| !c.is_ascii_digit() && c != 'A') {
+            return Err(line.error(&code[bad..bad + key.len_utf8()], "expected a key of the door keypad").into());
+        }
+        let n = line.parse(code.trim_end_matches('A'))?;
+        codes.push((code, n));
+    }
+    Ok(codes)
+}
+
+fn solve(data: &[(String, usize)], max_depth: usize) -> usize {
+    let solver = Solver::new(max_depth);
+    let memo = Mutex::new(HashMap::new()); // Wrap memo in a Mutex
+
+    data.par_iter()
+        .map(|(line, n)| {
+            let steps = {
+                let mut memo_guard = memo.lock().unwrap(); // Lock the Mutex to get mutable access
+                solver.walk_line(line, -1, &mut memo_guard, usize::MAX)
+            };
+            n * steps
+        })
+        .sum()
+}
+
+fn part1(codes: &[(String, usize)]) -> usize {
+    solve(codes, 2)
+}
+
+fn part2(codes: &[(String, usize)]) -> usize {
+    solve(codes, 25)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/21/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(126384, Day21.part1(&Day21.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(154115708116294_i64, Day21.part2(&Day21.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_keys_off_the_keypad() {
+        let error = Day21.parse("029A\n02é\n".as_bytes()).unwrap_err();
+
+        assert_eq!("line 2, column 3: 'é': expected a key of the door keypad", error.to_string());
+    }
+}
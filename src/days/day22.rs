@@ -0,0 +1,117 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    /// Initial secret number of every buyer.
+    type Input = Vec<u32>;
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey Market"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        let mut numbers = Vec::new();
+        for (index, text) in reader.lines().enumerate() {
+            let text = text?;
+            numbers.push(Line::new(index + 1, &text).parse(&text)?);
+        }
+        Ok(numbers)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn compute(mut num: u64, limit: i64) -> u64 {
+    for _ in 0..limit {
+        num = (num ^ (num * 64)) % 16_777_216;
+        num = (num ^ (num / 32)) % 16_777_216;
+        num = (num ^ (num * 2048)) % 16_777_216;
+    }
+    num
+}
+
+fn part1(numbers: &[u32]) -> u64 {
+    let mut result = 0;
+
+    for &num in numbers {
+        let y = compute(num as u64, 2000);
+        result += y;
+    }
+
+    result
+}
+
+fn get_sequence(mut x: u32, r: usize) -> (u32, Vec<u32>, Vec<i32>) {
+    let mut seq = Vec::new();
+    let mut diff = Vec::new();
+
+    for _ in 0..r {
+        let a = x % 10;
+
+        // Use u64 for intermediate calculations to prevent overflow
+        x = ((x as u64 ^ (x as u64 * 64)) % 16_777_216) as u32;
+        x = ((x as u64 ^ (x as u64 / 32)) % 16_777_216) as u32;
+        x = ((x as u64 ^ (x as u64 * 2048)) % 16_777_216) as u32;
+
+        let current = x % 10;
+        diff.push((current as i32) - (a as i32));
+        seq.push(current);
+    }
+    (x, seq, diff)
+}
+
+fn part2(numbers: &[u32]) -> u32 {
+    let mut dic: HashMap<Vec<i32>, u32> = HashMap::new();
+
+    for &x in numbers {
+        let (_, seq, diff_values) = get_sequence(x, 2000);
+        let mut seen: HashSet<Vec<i32>> = HashSet::new();
+
+        for i in 0..diff_values.len().saturating_sub(3) {
+            let pat: Vec<i32> = diff_values[i..i + 4].to_vec();
+            let point = seq.get(i + 3).copied().unwrap_or(0);
+
+            if !seen.contains(&pat) {
+                seen.insert(pat.clone());
+                *dic.entry(pat).or_insert(0) += point;
+            }
+        }
+    }
+
+    dic.values().max().copied().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/22/part1.txt");
+    const TEST2: &str = include_str!("../../examples/22/part2.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(37327623, Day22.part1(&Day22.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!(23, Day22.part2(&Day22.parse(TEST2.as_bytes())?)?);
+        Ok(())
+    }
+}
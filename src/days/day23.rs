@@ -0,0 +1,156 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    /// The computers each computer is directly connected to.
+    type Input = HashMap<String, HashSet<String>>;
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn title(&self) -> &'static str {
+        "LAN Party"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+fn find_or_create_node(nodes: &mut HashMap<String, HashSet<String>>, name: &str) {
+    nodes.entry(name.to_string()).or_default();
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<HashMap<String, HashSet<String>>> {
+    let mut nodes: HashMap<String, HashSet<String>> = HashMap::new();
+
+    // Parse input and build nodes with connections
+    for (index, text) in reader.lines().enumerate() {
+        let text = text?;
+        let (name1, name2) = Line::new(index + 1, &text).split_once("-")?;
+
+        // Ensure both nodes exist in the map
+        find_or_create_node(&mut nodes, name1);
+        find_or_create_node(&mut nodes, name2);
+
+        // Add the connection between the nodes
+        nodes.get_mut(name1).unwrap().insert(name2.to_string());
+        nodes.get_mut(name2).unwrap().insert(name1.to_string());
+    }
+    Ok(nodes)
+}
+
+fn computer_starts_with_t(nodes: &HashMap<String, HashSet<String>>) -> usize {
+    let mut connections = HashSet::new();
+
+    // Find valid connections
+    for (node, connections_set) in nodes {
+        for c1 in connections_set {
+            for c2 in &nodes[c1] {
+                if node == c1 || c1 == c2 || !connections_set.contains(c2) {
+                    continue;
+                }
+
+                let mut connection = vec![node.clone(), c1.clone(), c2.clone()];
+                connection.sort();
+                connections.insert(connection);
+            }
+        }
+    }
+
+    // Count connections where the name starts with "t"
+    connections
+        .iter()
+        .filter(|connection| connection.iter().any(|name| name.starts_with('t')))
+        .count()
+}
+
+fn part1(nodes: &HashMap<String, HashSet<String>>) -> usize {
+    computer_starts_with_t(nodes)
+}
+
+fn find_password(nodes: &HashMap<String, HashSet<String>>) -> Vec<String> {
+    let mut connections: Vec<Vec<String>> = Vec::new();
+
+    for (node_value, connections_set) in nodes {
+        let mut connection = vec![node_value.clone()];
+
+        for c1 in connections_set {
+            for c2 in connections_set {
+                if c1 == c2 {
+                    continue;
+                }
+
+                // Check if c1 is in c2's connections
+                if let Some(c2_connections) = nodes.get(c2) {
+                    if c2_connections.contains(c1) {
+                        connection.push(c1.clone());
+                        connection.push(c2.clone());
+                    }
+                }
+            }
+        }
+
+        if connection.len() <= 1 {
+            continue;
+        }
+
+        // Sort and deduplicate connection
+        connection.sort();
+        connection.dedup();
+        connections.push(connection);
+    }
+
+    // Find interconnections
+    let mut intercons: Vec<Vec<String>> = Vec::new();
+    for c in &connections {
+        let count = connections.iter().filter(|x| x == &c).count();
+        if c.len() == count {
+            intercons.push(c.clone());
+        }
+    }
+
+    // Find password (max length interconnection)
+    intercons
+        .iter()
+        .max_by_key(|x| x.len())
+        .unwrap_or(&Vec::new())
+        .clone()
+}
+
+fn part2(nodes: &HashMap<String, HashSet<String>>) -> String {
+    find_password(nodes).join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/23/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(7, Day23.part1(&Day23.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        assert_eq!("co,de,ka,ta", Day23.part2(&Day23.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
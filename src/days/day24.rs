@@ -0,0 +1,272 @@
+use crate::parse::Line;
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::result::Result::Ok;
+
+pub struct Day24;
+
+/// A gate as (input, input, gate, output).
+pub type Gate = (String, String, String, String);
+
+impl Solution for Day24 {
+    /// Initial wire values, and every gate.
+    type Input = (HashMap<String, u32>, Vec<Gate>);
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn title(&self) -> &'static str {
+        "Crossed Wires"
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
+
+fn work(values: &mut HashMap<String, u32>, ops: &[Gate]) -> usize {
+    let mut missing = 0;
+
+    for (inp1, inp2, gate, out) in ops {
+        if values.contains_key(out) {
+            continue;
+        }
+
+        if !values.contains_key(inp1) || !values.contains_key(inp2) {
+            missing += 1;
+            continue;
+        }
+
+        let result = match gate.as_str() {
+            "OR" => values[inp1] | values[inp2],
+            "AND" => values[inp1] & values[inp2],
+            "XOR" => (values[inp1] as i64 ^ values[inp2] as i64) as u32,
+            _ => panic!("undefined gate {}", gate),
+        };
+
+        values.insert(out.clone(), result);
+    }
+
+    missing
+}
+
+fn part1((values, ops): &(HashMap<String, u32>, Vec<Gate>)) -> Result<u64> {
+    let mut values = values.clone();
+    loop {
+        let known = values.len();
+        if work(&mut values, ops) == 0 {
+            break;
+        }
+        // `parse_input` checks every input is driven, so a pass without progress means a loop
+        if values.len() == known {
+            let stuck: Vec<&str> =
+                ops.iter().map(|(_, _, _, out)| out.as_str()).filter(|out| !values.contains_key(*out)).collect();
+            bail!("gates driving {} feed each other in a loop", stuck.join(","));
+        }
+    }
+
+    let mut output = 0;
+    for (key, value) in &values {
+        if let Some(index) = key.strip_prefix('z') {
+            let index: usize = index.parse().with_context(|| format!("invalid output wire {}", key))?;
+            let shifted_value = (*value as u64) << index;
+            output |= shifted_value;
+        }
+    }
+
+    Ok(output)
+}
+
+fn parse_input<R: BufRead>(
+    reader: R,
+) -> Result<(HashMap<String, u32>, Vec<Gate>)> {
+    let mut lines = reader.lines().enumerate();
+
+    // Parse values
+    let mut values: HashMap<String, u32> = HashMap::new();
+    let mut ops: Vec<Gate> = Vec::new();
+
+    // Read the first part of the input
+    for (index, text) in lines.by_ref() {
+        let text = text?;
+        if text.trim().is_empty() {
+            break;
+        }
+        let line = Line::new(index + 1, &text);
+        let (wire, value) = line.split_once(": ")?;
+        values.insert(wire.to_string(), line.parse(value)?);
+    }
+
+    // Read the second part of the input, keeping each gate's line to point at undriven inputs
+    let mut gate_lines = Vec::new();
+    for (index, text) in lines {
+        let text = text?;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let line = Line::new(index + 1, &text);
+        let (expr_text, out) = line.split_once(" -> ")?;
+        let expr: Vec<&str> = expr_text.split_whitespace().collect();
+        if expr.len() != 3 {
+            return Err(line.error(expr_text, "expected '<wire> <gate> <wire>'").into());
+        }
+        if !["AND", "OR", "XOR"].contains(&expr[1]) {
+            return Err(line.error(expr[1], "expected AND, OR or XOR").into());
+        }
+        ops.push((expr[0].to_string(), expr[2].to_string(), expr[1].to_string(), out.to_string()));
+        gate_lines.push((index + 1, text));
+    }
+
+    let outputs: HashSet<&str> = ops.iter().map(|(_, _, _, out)| out.as_str()).collect();
+    for (number, text) in &gate_lines {
+        let line = Line::new(*number, text);
+        for wire in text.split_whitespace().take(3).step_by(2) {
+            if !values.contains_key(wire) && !outputs.contains(wire) {
+                return Err(line.error(wire, "no initial value or gate drives this wire").into());
+            }
+        }
+    }
+    Ok((values, ops))
+}
+
+fn part2((_, ops): &(HashMap<String, u32>, Vec<Gate>)) -> Result<String> {
+    // The highest output wire carries the final carry, so it has to come from an OR gate
+    let last_output = ops
+        .iter()
+        .map(|(_, _, _, out)| out)
+        .filter(|out| out.starts_with('z'))
+        .max()
+        .context("circuit has no output wires")?
+        .clone();
+
+    // Track usage and errors
+    let mut usage: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut errors: Vec<String> = Vec::new();
+
+    for (inp1, inp2, gate, _) in ops {
+        usage.entry(inp1.clone()).or_default().insert(gate.clone());
+        usage.entry(inp2.clone()).or_default().insert(gate.clone());
+    }
+
+    for (inp1, inp2, gate, out) in ops {
+        if out == &last_output {
+            if inp1.starts_with('x')
+                || inp1.starts_with('y')
+                || inp2.starts_with('x')
+                || inp2.starts_with('y')
+                || gate != "OR"
+            {
+                errors.push(out.clone());
+            }
+            continue;
+        }
+
+        if out == "z00" {
+            if [&inp1[..], &inp2[..]] != ["x00", "y00"] || gate != "XOR" {
+                errors.push(out.clone());
+            }
+            continue;
+        }
+
+        if inp1 == "x00" || inp1 == "y00" || inp2 == "x00" || inp2 == "y00" {
+            if ((inp1.starts_with('x') && inp2.starts_with('y'))
+                || (inp1.starts_with('y') && inp2.starts_with('x')))
+                && gate != "XOR"
+                && gate != "AND"
+            {
+                errors.push(out.clone());
+            }
+            continue;
+        }
+
+        match gate.as_str() {
+            "XOR" => {
+                if inp1.starts_with('x') || inp1.starts_with('y') {
+                    if !inp2.starts_with('x') && !inp2.starts_with('y') {
+                        errors.push(out.clone());
+                    }
+                    if out.starts_with('z') {
+                        errors.push(out.clone());
+                    }
+                    if !usage
+                        .get(out)
+                        .is_some_and(|g| g.contains("AND") && g.contains("XOR"))
+                    {
+                        errors.push(out.clone());
+                    }
+                } else if !out.starts_with('z') {
+                    errors.push(out.clone());
+                }
+            }
+            "OR" => {
+                if inp1.starts_with('x')
+                    || inp1.starts_with('y')
+                    || inp2.starts_with('x')
+                    || inp2.starts_with('y')
+                    || out.starts_with('z')
+                {
+                    errors.push(out.clone());
+                }
+                if !usage
+                    .get(out)
+                    .is_some_and(|g| g.contains("AND") && g.contains("XOR"))
+                {
+                    errors.push(out.clone());
+                }
+            }
+            "AND" => {
+                if (inp1.starts_with('x') || inp1.starts_with('y'))
+                    && !inp2.starts_with('x')
+                    && !inp2.starts_with('y')
+                {
+                    errors.push(out.clone());
+                }
+                if !usage.get(out).is_some_and(|g| g.contains("OR")) {
+                    errors.push(out.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    errors.sort();
+    errors.dedup();
+
+    Ok(errors.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/24/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(2024, Day24.part1(&Day24.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_undriven_and_looping_wires() -> Result<()> {
+        let error = Day24.parse("x00: 1\n\nx00 AND y00 -> z00\n".as_bytes()).unwrap_err();
+        assert_eq!("line 3, column 9: 'y00': no initial value or gate drives this wire", error.to_string());
+
+        let input = Day24.parse("x00: 1\n\nx00 AND a -> b\nb OR x00 -> a\nb XOR x00 -> z00\n".as_bytes())?;
+        assert_eq!("gates driving b,a,z00 feed each other in a loop", Day24.part1(&input).unwrap_err().to_string());
+        Ok(())
+    }
+
+    // The example circuit is not an adder, so part 2 has nothing to check against it.
+}
@@ -0,0 +1,110 @@
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day25;
+
+/// Pin heights of every key and lock, and the height of a schematic.
+pub struct Schematics {
+    pub keys: Vec<Vec<i32>>,
+    pub locks: Vec<Vec<i32>>,
+    pub height: usize,
+}
+
+impl Solution for Day25 {
+    type Input = Schematics;
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn title(&self) -> &'static str {
+        "Code Chronicle"
+    }
+
+    fn parts(&self) -> u8 {
+        1
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        bail!("day 25 has no part 2")
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Schematics> {
+    // Read lines efficiently and split raw data into sections
+    let raw_data: String = reader.lines().collect::<Result<Vec<_>, _>>()?.join("\n");
+    let sections: Vec<&str> = raw_data.split("\n\n").collect();
+
+    // Extract dimensions
+    let width = sections[0].lines().next().context("empty schematic")?.len();
+    let height = sections[0].lines().count();
+
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+
+    // Process each section
+    for section in sections {
+        let mut pin_count = vec![-1; width];
+        let mut is_lock = false;
+
+        // Process pins directly without collecting into a Vec<char>
+        for (i, ch) in section.chars().filter(|&c| c != '\n').enumerate() {
+            if ch == '#' {
+                pin_count[i % width] += 1;
+                // Check if this section represents a lock
+                if i == 0 {
+                    is_lock = true;
+                }
+            }
+        }
+
+        // Categorize as keys or locks
+        if is_lock {
+            locks.push(pin_count);
+        } else {
+            keys.push(pin_count);
+        }
+    }
+
+    Ok(Schematics { keys, locks, height })
+}
+
+fn part1(Schematics { keys, locks, height }: &Schematics) -> usize {
+    let mut count = 0;
+
+    for key in keys {
+        for lock in locks {
+            if key
+                .iter()
+                .zip(lock.iter())
+                .all(|(&k, &l)| k + l <= (*height as i32 - 2))
+            {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/25/example.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        assert_eq!(3, Day25.part1(&Day25.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+}
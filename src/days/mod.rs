@@ -0,0 +1,84 @@
+//! Solvers for every puzzle day plus the registry the `aoc` runner dispatches through.
+
+use crate::Solver;
+use anyhow::*;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every solved day, in order.
+pub static DAYS: [&dyn Solver; 25] = [
+    &day01::Day01,
+    &day02::Day02::PUZZLE,
+    &day03::Day03::PUZZLE,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14::PUZZLE,
+    &day15::Day15,
+    &day16::Day16::PUZZLE,
+    &day17::Day17,
+    &day18::Day18::PUZZLE,
+    &day19::Day19,
+    &day20::Day20::PUZZLE,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Looks up a registered day by its number (1-25).
+pub fn get(day: u8) -> Option<&'static dyn Solver> {
+    DAYS.iter().copied().find(|solver| solver.day() == day)
+}
+
+/// Builds `day` with puzzle parameters such as a grid size, for inputs that differ from the real
+/// puzzle (usually examples). An empty table keeps the registered solver and yields `None`.
+pub fn configure(day: u8, params: &toml::Table) -> Result<Option<Box<dyn Solver>>> {
+    if params.is_empty() {
+        return Ok(None);
+    }
+
+    let params = toml::Value::Table(params.clone());
+    let solver: Box<dyn Solver> = match day {
+        2 => Box::new(params.try_into::<day02::Day02>()?),
+        3 => Box::new(params.try_into::<day03::Day03>()?),
+        14 => Box::new(params.try_into::<day14::Day14>()?),
+        16 => Box::new(params.try_into::<day16::Day16>()?),
+        18 => Box::new(params.try_into::<day18::Day18>()?),
+        20 => Box::new(params.try_into::<day20::Day20>()?),
+        _ => bail!("day {} takes no parameters", day),
+    };
+    Ok(Some(solver))
+}
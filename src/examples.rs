@@ -0,0 +1,123 @@
+//! Example inputs kept as fixtures in `examples/NN/`, each `name.txt` paired with a `name.toml`
+//! holding the answers the puzzle text gives for it and any parameters the example needs:
+//!
+//! ```toml
+//! part1 = 22
+//! part2 = "6,1"
+//!
+//! [params]
+//! dest = [6, 6]
+//! limit = 12
+//! ```
+//!
+//! A part without an answer is not checked. `build.rs` turns every fixture into a `cargo test`.
+
+use crate::answers::{Expected, Verdict};
+use crate::days;
+use crate::Answer;
+use anyhow::*;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// One example input with its expected answers.
+#[derive(Clone, Debug)]
+pub struct Example {
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    pub expected: Expected,
+    pub params: toml::Table,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Sidecar {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    #[serde(default)]
+    params: toml::Table,
+}
+
+pub fn example_path(dir: &Path, day: u8, name: &str) -> PathBuf {
+    dir.join(format!("{:02}", day)).join(format!("{}.txt", name))
+}
+
+/// Loads the example `name` of `day` from `dir` along with its `.toml` sidecar.
+pub fn load(dir: &Path, day: u8, name: &str) -> Result<Example> {
+    let path = example_path(dir, day, name);
+    let input = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+
+    let path = path.with_extension("toml");
+    let text = fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+    let sidecar: Sidecar = toml::from_str(&text).with_context(|| format!("invalid example file {}", path.display()))?;
+
+    Ok(Example {
+        day,
+        name: name.to_string(),
+        input,
+        expected: Expected { part1: sidecar.part1, part2: sidecar.part2 },
+        params: sidecar.params,
+    })
+}
+
+/// Names of every example of `day`, sorted.
+pub fn names(dir: &Path, day: u8) -> Result<Vec<String>> {
+    let dir = dir.join(format!("{:02}", day));
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("cannot list {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            names.extend(path.file_stem().and_then(|stem| stem.to_str()).map(str::to_string));
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+impl Example {
+    /// Solves every part that has an expected answer and fails on the first mismatch.
+    pub fn check(&self) -> Result<()> {
+        // A freshly scaffolded example has no answers yet, and its day may not be registered.
+        if self.expected == Expected::default() {
+            return Ok(());
+        }
+
+        let configured = days::configure(self.day, &self.params)?;
+        let solver = match &configured {
+            Some(solver) => solver.as_ref(),
+            None => days::get(self.day)
+                .with_context(|| format!("day {} is not solved or not registered in days::DAYS", self.day))?,
+        };
+
+        let parts: Vec<u8> = (1..=2).filter(|&part| self.expected.part(part).is_some()).collect();
+        let solved = solver
+            .solve_timed(&mut self.input.as_bytes(), &parts)
+            .with_context(|| format!("day {:02} example {}", self.day, self.name))?;
+
+        for (&part, timed) in parts.iter().zip(solved) {
+            let Some(expected) = self.expected.part(part) else { continue };
+            let answer = timed
+                .with_context(|| format!("day {:02} example {} part {}", self.day, self.name, part))?
+                .answer;
+
+            if let Verdict::Fail { expected } = Verdict::check(Some(expected), &answer) {
+                bail!(
+                    "day {:02} example {} part {}: expected {}, got {}",
+                    self.day,
+                    self.name,
+                    part,
+                    expected,
+                    answer
+                );
+            }
+        }
+        Ok(())
+    }
+}
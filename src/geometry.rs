@@ -0,0 +1,166 @@
+//! Points and facings on a puzzle map.
+
+use serde::Deserialize;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// Position of a cell: `row` counts down from the top, `col` right from the left.
+///
+/// Signed so that stepping off an edge still gives a point; the grid reports it as outside.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const fn new(row: isize, col: isize) -> Point {
+        Point { row, col }
+    }
+
+    /// Number of orthogonal steps between the two points.
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The point on a `height` by `width` map that wraps around at the edges.
+    pub fn wrap(self, height: isize, width: isize) -> Point {
+        Point::new(self.row.rem_euclid(height), self.col.rem_euclid(width))
+    }
+
+    /// The neighbouring point in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+/// One of the four orthogonal facings, drawn as `^`, `>`, `v` and `<` on puzzle maps.
+///
+/// Read from parameter tables as `"up"`, `"right"`, `"down"` or `"left"`, or by compass point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    #[serde(alias = "north")]
+    Up,
+    #[serde(alias = "east")]
+    Right,
+    #[serde(alias = "south")]
+    Down,
+    #[serde(alias = "west")]
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The direction drawn as `c`, if it is one of `^>v<`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// The step one cell in this direction.
+    pub const fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Right => Point::new(0, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Whether this direction moves along a column rather than a row.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_parses_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(Some(direction), Direction::from_char(direction.to_char()));
+            assert_eq!(direction, direction.turn_right().turn_left());
+            assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            assert_eq!(Point::default(), direction.delta() + direction.reverse().delta());
+        }
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(None, Direction::from_char('x'));
+    }
+
+    #[test]
+    fn measures_and_wraps_points() {
+        assert_eq!(7, Point::new(1, -2).manhattan(Point::new(-2, 2)));
+        assert_eq!(Point::new(6, 0), Point::new(-1, 11).wrap(7, 11));
+        assert_eq!(Point::new(2, 3), Point::new(2, 2).step(Direction::Right));
+    }
+}
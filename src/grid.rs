@@ -0,0 +1,238 @@
+//! Rectangular grids of cells, as most puzzle maps are.
+
+use crate::geometry::{Direction, Point};
+use crate::parse::Line;
+use anyhow::*;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// Steps to all eight neighbours, clockwise from up.
+const NEIGHBOURS8: [Point; 8] = [
+    Point::new(-1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+    Point::new(1, 0),
+    Point::new(1, -1),
+    Point::new(0, -1),
+    Point::new(-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Builds a grid from its rows, which must all be as long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            bail!("row {} has {} cells, the first row has {}", row + 1, rows[row].len(), width);
+        }
+
+        let height = rows.len();
+        Ok(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Reads lines of `reader` up to a blank line or the end, turning each character into a
+    /// cell with `cell`; characters it returns `None` for are reported as errors.
+    pub fn parse_with<R: BufRead>(reader: R, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut rows = Vec::new();
+        for (index, text) in reader.lines().enumerate() {
+            let text = text?;
+            let text = text.trim_end();
+            if text.is_empty() {
+                break;
+            }
+
+            let line = Line::new(index + 1, text);
+            let row = text
+                .char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| line.error(&text[i..i + c.len_utf8()], "unexpected character")))
+                .collect::<Result<Vec<T>, _>>()?;
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.height as isize).contains(&point.row) && (0..self.width as isize).contains(&point.col)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point).then(|| point.row as usize * self.width + point.col as usize)
+    }
+
+    /// The cell at `point`, or `None` outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as isize;
+        (0..self.height as isize).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The orthogonal neighbours of `point` inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.iter().map(move |&direction| point.step(direction)).filter(|&next| self.contains(next))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS8.iter().map(move |&step| point + step).filter(|&next| self.contains(next))
+    }
+
+    /// The first point holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(point, _)| point)
+    }
+
+    /// Every point holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(point, _)| point)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `col`, top to bottom; panics if `col` is out of range, as `row` does.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} is out of range for a grid {} wide", col, self.width);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl Grid<char> {
+    /// Reads a character grid up to a blank line or the end of `reader`.
+    pub fn parse<R: BufRead>(reader: R) -> Result<Grid<char>> {
+        Grid::parse_with(reader, Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the {}x{} grid", point, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the {}x{} grid", point, width, height),
+        }
+    }
+}
+
+/// Draws the grid as it appears in puzzle inputs: one line per row, cells side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        std::result::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.#\n.S.\n#..\n\nmoves";
+
+    #[test]
+    fn parses_up_to_blank_line() -> Result<()> {
+        let mut reader = MAP.as_bytes();
+        let grid = Grid::parse(&mut reader)?;
+
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(Some(Point::new(1, 1)), grid.find(&'S'));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!("#.#\n.S.\n#..\n", grid.to_string());
+        assert_eq!("moves", reader.lines().next().unwrap()?);
+        Ok(())
+    }
+
+    #[test]
+    fn neighbours_stay_inside() -> Result<()> {
+        let grid = Grid::parse(MAP.as_bytes())?;
+
+        assert_eq!(vec![Point::new(0, 1), Point::new(1, 0)], grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>());
+        assert_eq!(8, grid.neighbours8(Point::new(1, 1)).count());
+        assert_eq!(vec!['#', '.', '#'], grid.column(0).copied().collect::<Vec<_>>());
+        assert_eq!(2, grid.find_all(&'#').filter(|point| point.col == 0).count());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of range for a grid 3 wide")]
+    fn column_past_the_edge_panics() {
+        let grid = Grid::parse(MAP.as_bytes()).unwrap();
+
+        grid.column(3).for_each(drop);
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let err = Grid::parse_with("12\n3x\n".as_bytes(), |c| c.to_digit(10)).unwrap_err();
+        assert_eq!("line 2, column 2: 'x': unexpected character", err.to_string());
+
+        assert!(Grid::parse("ab\nc\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+//! Finds and reads a day's puzzle input.
+//!
+//! The input comes from, in order: `--input <path>` on the command line (`-` reads stdin), the
+//! `AOC_INPUT_DIR` directory, `input/NN.txt` under the working directory, `input/NN.txt` in the
+//! crate root so binaries also work when launched from elsewhere, and finally the input cache that
+//! `aoc fetch` fills (`input/2024/NN.txt` in the crate root), whose inputs are checked against their
+//! checksums when read; a cached input without a checksum is refused until `aoc fetch` records one.
+
+use crate::cache::{InputCache, YEAR};
+use anyhow::*;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const INPUT_DIR: &str = "input";
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// The day's input in the cache `aoc fetch` fills, checked against its checksum when read.
+    Cache(u8),
+}
+
+impl InputSource {
+    /// Picks the input of `day`, using `input` (the `--input` value) when given.
+    pub fn resolve(day: u8, input: Option<&str>) -> Result<InputSource> {
+        match input {
+            Some("-") => return Ok(InputSource::Stdin),
+            Some(path) => {
+                let path = PathBuf::from(path);
+                ensure!(path.is_file(), "input file {} does not exist", path.display());
+                return Ok(InputSource::File(path));
+            }
+            None => {}
+        }
+
+        let candidates = candidates(day, std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from));
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) if *path == cache().path(YEAR, day) => Ok(InputSource::Cache(day)),
+            Some(path) => Ok(InputSource::File(path.clone())),
+            None => {
+                let searched: Vec<String> = candidates.iter().map(|path| path.display().to_string()).collect();
+                bail!("no input for day {:02}, searched: {}", day, searched.join(", "))
+            }
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).context("cannot read stdin")?;
+                Ok(input)
+            }
+            InputSource::File(path) => {
+                fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))
+            }
+            InputSource::Cache(day) => cache().read(YEAR, *day),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Cache(day) => write!(f, "{}", cache().path(YEAR, *day).display()),
+        }
+    }
+}
+
+/// Paths tried for `day` when no `--input` is given, most specific first.
+fn candidates(day: u8, env_dir: Option<PathBuf>) -> Vec<PathBuf> {
+    let file = format!("{:02}.txt", day);
+    let mut candidates: Vec<PathBuf> = env_dir.into_iter().map(|dir| dir.join(&file)).collect();
+    for dir in [Path::new(INPUT_DIR).to_path_buf(), cache_dir()] {
+        let path = dir.join(&file);
+        if !candidates.contains(&path) {
+            candidates.push(path);
+        }
+    }
+    candidates.push(cache().path(YEAR, day));
+    candidates
+}
+
+/// Root of the input cache, `input/` in the crate root.
+pub fn cache_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_DIR)
+}
+
+fn cache() -> InputCache {
+    InputCache::new(cache_dir())
+}
+
+/// Takes `--input <path>` out of `args`, leaving the other arguments in place.
+pub fn take_input_arg(args: &mut Vec<String>) -> Result<Option<String>> {
+    let Some(index) = args.iter().position(|arg| arg == "--input") else {
+        return Ok(None);
+    };
+    ensure!(index + 1 < args.len(), "--input needs a path, or - for stdin");
+
+    let value = args.remove(index + 1);
+    args.remove(index);
+    Ok(Some(value))
+}
+
+/// Reads the puzzle input of a day binary, honouring `--input` and `AOC_INPUT_DIR`.
+pub fn load_input(day: &str) -> Result<String> {
+    let number: u8 = day.parse().with_context(|| format!("invalid day '{}'", day))?;
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let input = take_input_arg(&mut args)?;
+
+    InputSource::resolve(number, input.as_deref())?.read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_dir_is_searched_first() {
+        let candidates = candidates(7, Some(PathBuf::from("/tmp/aoc")));
+
+        assert_eq!(PathBuf::from("/tmp/aoc/07.txt"), candidates[0]);
+        assert_eq!(PathBuf::from("input/07.txt"), candidates[1]);
+    }
+
+    #[test]
+    fn missing_input_names_searched_paths() {
+        let err = InputSource::resolve(0, None).unwrap_err().to_string();
+
+        assert!(err.contains("input/00.txt"), "{}", err);
+    }
+}
@@ -1,7 +1,135 @@
+use anyhow::*;
+use std::any::Any;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+pub mod answer;
+pub mod answers;
+pub mod cache;
+pub mod days;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+pub mod union_find;
+pub mod word_search;
+
+pub use answer::Answer;
+pub use input::load_input;
+pub use parse::ParseError;
+pub use report::run_day;
+
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
 }
 
+/// A solved puzzle day: the input is parsed once and both parts are answered from it.
+pub trait Solution {
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+
+    /// Number of parts the puzzle has; only the last day stops at one.
+    fn parts(&self) -> u8 {
+        2
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+/// Object-safe view of a [`Solution`] so days of different types can share the registry.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parts(&self) -> u8;
+
+    /// Parses `reader` into the day's input, type-erased so it can be handed back to
+    /// [`Solver::part_any`]; lets callers such as benchmarks time parsing on its own.
+    fn parse_any(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>>;
+
+    /// Solves `part` (1 or 2) on an input produced by [`Solver::parse_any`].
+    fn part_any(&self, input: &dyn Any, part: u8) -> Result<Answer>;
+
+    /// Parses `reader` once and solves each of `parts` (1 or 2) on it, timing parsing and each
+    /// part separately. Fails only if the input does not parse; a part that fails does not stop
+    /// the others.
+    fn solve_timed(&self, reader: &mut dyn BufRead, parts: &[u8]) -> Result<Vec<Result<Timed>>> {
+        if let Some(&part) = parts.iter().find(|part| !(1..=self.parts()).contains(part)) {
+            bail!("day {} has no part {}", self.day(), part);
+        }
+
+        let start = Instant::now();
+        let input = self.parse_any(reader)?;
+        let parse = start.elapsed();
+
+        let solve = |part| {
+            let start = Instant::now();
+            let answer = self.part_any(input.as_ref(), part)?;
+            Ok(Timed { answer, parse, solve: start.elapsed() })
+        };
+        Ok(parts.iter().map(|&part| solve(part)).collect())
+    }
+
+    /// Parses `reader` and solves `part` (1 or 2).
+    fn solve(&self, reader: &mut dyn BufRead, part: u8) -> Result<Answer> {
+        self.solve_timed(reader, &[part])?.remove(0).map(|timed| timed.answer)
+    }
+}
+
+/// The answer to one part with the time spent parsing the input, shared by every part solved
+/// from it, and solving the part.
+#[derive(Clone, Debug)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl<S: Solution + Sync> Solver for S
+where
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Solution::title(self)
+    }
+
+    fn parts(&self) -> u8 {
+        Solution::parts(self)
+    }
+
+    fn parse_any(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(reader)?))
+    }
+
+    fn part_any(&self, input: &dyn Any, part: u8) -> Result<Answer> {
+        let input = input.downcast_ref::<S::Input>().context("input was parsed by another day")?;
+        match part {
+            1 => self.part1(input),
+            2 if part <= Solution::parts(self) => self.part2(input),
+            _ => bail!("day {} has no part {}", Solution::day(self), part),
+        }
+    }
+}
+
+/// Reads the whole puzzle input, for days that work on the raw text.
+pub fn read_input<R: BufRead>(mut reader: R) -> Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
 // Additional common functions
 
 #[cfg(test)]
@@ -0,0 +1,105 @@
+//! Errors for malformed puzzle input that point at the line and column at fault.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A piece of puzzle input a day could not make sense of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, counted from 1.
+    pub line: usize,
+    /// Column of the offending text in its line, counted from 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: '{}': {}", self.line, self.column, self.text, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One numbered line of input, so fields parsed out of it report where they came from.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Line<'a> {
+        Line { number, text }
+    }
+
+    /// Error about `field`, which should be a slice of this line to get its column right;
+    /// anything else is reported at column 1.
+    pub fn error(&self, field: &str, message: impl Into<String>) -> ParseError {
+        let offset = (field.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = match self.text.get(..offset) {
+            Some(before) if offset + field.len() <= self.text.len() => before.chars().count() + 1,
+            _ => 1,
+        };
+        ParseError { line: self.number, column, text: field.to_string(), message: message.into() }
+    }
+
+    /// Parses `field`, a slice of this line, ignoring surrounding whitespace.
+    pub fn parse<T: FromStr>(&self, field: &str) -> Result<T, ParseError>
+    where
+        T::Err: fmt::Display,
+    {
+        let field = field.trim();
+        field.parse().map_err(|err: T::Err| self.error(field, err.to_string()))
+    }
+
+    /// Parses every one of `fields`, slices of this line.
+    pub fn parse_each<T: FromStr>(&self, fields: impl IntoIterator<Item = &'a str>) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: fmt::Display,
+    {
+        fields.into_iter().map(|field| self.parse(field)).collect()
+    }
+
+    /// Splits `field`, a slice of this line, around the first `separator`.
+    pub fn split(&self, field: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        field.split_once(separator).ok_or_else(|| self.error(field, format!("expected '{}'", separator)))
+    }
+
+    /// Splits the whole line around the first `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.split(self.text, separator)
+    }
+
+    /// The decimal digit at byte `index` of the line.
+    pub fn digit(&self, index: usize) -> Result<u32, ParseError> {
+        let digit = self.text.get(index..index + 1).unwrap_or_default();
+        digit.chars().next().and_then(|c| c.to_digit(10)).ok_or_else(|| self.error(digit, "expected a digit"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_offending_field() {
+        let line = Line::new(3, "12   x4");
+        let (_, right) = line.split_once("   ").unwrap();
+        let err = line.parse::<i32>(right).unwrap_err();
+
+        assert_eq!((3, 6, "x4"), (err.line, err.column, err.text.as_str()));
+        assert_eq!("line 3, column 6: 'x4': invalid digit found in string", err.to_string());
+    }
+
+    #[test]
+    fn reports_missing_separator_and_digits() {
+        let line = Line::new(1, "2333a");
+
+        assert_eq!(1, line.split_once(",").unwrap_err().column);
+        assert_eq!(5, line.digit(4).unwrap_err().column);
+        assert_eq!(2, line.digit(0).unwrap());
+    }
+}
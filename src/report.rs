@@ -0,0 +1,165 @@
+//! Output of a day binary: readable text, or structured records for dashboards.
+
+use crate::input::{take_input_arg, InputSource};
+use crate::{start_day, Answer, Solver, Timed};
+use anyhow::*;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("invalid format '{}', expected json, csv or text", s),
+        }
+    }
+}
+
+/// One solved part; times are in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: u8, part: u8, timed: Timed) -> Record {
+        Record {
+            day,
+            part,
+            answer: timed.answer,
+            parse_ns: timed.parse.as_nanos() as u64,
+            solve_ns: timed.solve.as_nanos() as u64,
+        }
+    }
+}
+
+/// Writes `records` as a JSON array or as CSV with a header row.
+pub fn write_records(out: &mut dyn Write, format: Format, records: &[Record]) -> Result<()> {
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            writeln!(out, "day,part,answer,parse_ns,solve_ns")?;
+            for record in records {
+                writeln!(
+                    out,
+                    "{},{},{},{},{}",
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer.to_string()),
+                    record.parse_ns,
+                    record.solve_ns
+                )?;
+            }
+        }
+        Format::Text => {
+            for record in records {
+                writeln!(out, "{}", if record.part == 1 { "=== Part 1 ===" } else { "\n=== Part 2 ===" })?;
+                writeln!(
+                    out,
+                    "Parse: {:.2?}  Solve: {:.2?}",
+                    Duration::from_nanos(record.parse_ns),
+                    Duration::from_nanos(record.solve_ns)
+                )?;
+                writeln!(out, "Result = {}", record.answer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Quotes a CSV field when it holds a separator, as comma-joined answers do.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Takes `--format <json|csv|text>` out of `args`; text when absent.
+pub fn take_format_arg(args: &mut Vec<String>) -> Result<Format> {
+    let Some(index) = args.iter().position(|arg| arg == "--format") else {
+        return Ok(Format::Text);
+    };
+    ensure!(index + 1 < args.len(), "--format needs a value: json, csv or text");
+
+    let format = args.remove(index + 1).parse()?;
+    args.remove(index);
+    Ok(format)
+}
+
+/// Main of a day binary: reads `--input` and `--format`, then solves every part of `solver` on
+/// the puzzle input.
+pub fn run_day(solver: &dyn Solver) -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let input = take_input_arg(&mut args)?;
+    let format = take_format_arg(&mut args)?;
+    ensure!(args.is_empty(), "unexpected argument '{}'", args[0]);
+
+    if format == Format::Text {
+        start_day(&solver.day().to_string());
+    }
+    let input = InputSource::resolve(solver.day(), input.as_deref())?.read()?;
+
+    let parts: Vec<u8> = (1..=solver.parts()).collect();
+    let mut records = Vec::new();
+    for (part, timed) in parts.iter().zip(solver.solve_timed(&mut input.as_bytes(), &parts)?) {
+        records.push(Record::new(solver.day(), *part, timed?));
+    }
+
+    write_records(&mut io::stdout().lock(), format, &records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record { day: 18, part: 1, answer: Answer::Int(22), parse_ns: 5, solve_ns: 70 },
+            Record { day: 18, part: 2, answer: Answer::from("6,1"), parse_ns: 4, solve_ns: 90 },
+        ]
+    }
+
+    #[test]
+    fn writes_csv_with_quoted_answers() -> Result<()> {
+        let mut out = Vec::new();
+        write_records(&mut out, Format::Csv, &records())?;
+
+        assert_eq!("day,part,answer,parse_ns,solve_ns\n18,1,22,5,70\n18,2,\"6,1\",4,90\n", String::from_utf8(out)?);
+        Ok(())
+    }
+
+    #[test]
+    fn writes_json_records() -> Result<()> {
+        let mut out = Vec::new();
+        write_records(&mut out, Format::Json, &records())?;
+        let json: serde_json::Value = serde_json::from_slice(&out)?;
+
+        assert_eq!(22, json[0]["answer"]);
+        assert_eq!("6,1", json[1]["answer"]);
+        assert_eq!(90, json[1]["solve_ns"]);
+        Ok(())
+    }
+}
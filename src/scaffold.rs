@@ -0,0 +1,148 @@
+//! Starts a new day from the templates in `templates/`: a `Solution` module under `src/days/`,
+//! registered in `src/days/mod.rs`, and a day binary that runs it.
+
+use crate::examples::{example_path, EXAMPLES_DIR};
+use anyhow::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The day module template, compiled in so `aoc new` works from any directory.
+pub const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+/// The day binary template.
+pub const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs");
+
+/// Placeholder for the zero-padded day, as in `DayNN` and `examples/NN/`.
+const DAY_MARKER: &str = "NN";
+/// The body of `Solution::day`, which takes the day as a plain number.
+const DAY_NUMBER_MARKER: &str = "-> u8 {\n        NN\n";
+
+const EXAMPLE_ANSWERS: &str = "\
+# Answers the puzzle text gives for example.txt; uncomment once known.
+# part1 = 0
+# part2 = 0
+";
+
+/// Fills the day module template in for `day`: the type name, the day number, and an example
+/// test reading the `examples/NN/example.txt` fixture.
+pub fn render_day(day: u8) -> Result<String> {
+    ensure!((1..=25).contains(&day), "day must be between 1 and 25, got {}", day);
+    ensure!(DAY_TEMPLATE.contains(DAY_NUMBER_MARKER), "day template has no day number to fill in");
+
+    let rendered = DAY_TEMPLATE.replace(DAY_NUMBER_MARKER, &format!("-> u8 {{\n        {}\n", day));
+    Ok(rendered.replace(DAY_MARKER, &format!("{:02}", day)))
+}
+
+/// Fills the binary template in for `day`.
+pub fn render_bin(day: u8) -> Result<String> {
+    ensure!((1..=25).contains(&day), "day must be between 1 and 25, got {}", day);
+
+    Ok(BIN_TEMPLATE.replace(DAY_MARKER, &format!("{:02}", day)))
+}
+
+/// The day registry, relative to the crate root.
+pub const REGISTRY: &str = "src/days/mod.rs";
+
+const DAYS_START: &str = "pub static DAYS: [&dyn Solver; ";
+
+/// `registry`, the text of `src/days/mod.rs`, with `day`'s module declared and its solver added to
+/// `DAYS`, both in day order.
+pub fn register(registry: &str, day: u8) -> Result<String> {
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    let day_of = |line: &str, prefix: &str| line.trim_start().strip_prefix(prefix)?.get(..2)?.parse::<u8>().ok();
+
+    // `pub mod dayNN;` declarations
+    let modules: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line, "pub mod day")?)))
+        .collect();
+    ensure!(modules.iter().all(|&(_, known)| known != day), "day {:02} is already registered", day);
+    let first = modules.first().context("registry declares no day modules")?.0;
+    let at = modules.iter().rfind(|&&(_, known)| known < day).map_or(first, |&(index, _)| index + 1);
+    lines.insert(at, format!("pub mod day{:02};", day));
+
+    // `&dayNN::...` entries of `DAYS`, whose length goes up by one
+    let start = lines.iter().position(|line| line.starts_with(DAYS_START)).context("registry has no DAYS")?;
+    let (count, rest) = lines[start][DAYS_START.len()..].split_once(']').context("DAYS has no length")?;
+    let count: usize = count.parse().context("DAYS has no length")?;
+    lines[start] = format!("{}{}]{}", DAYS_START, count + 1, rest);
+
+    let end = start + lines[start..].iter().position(|line| line.trim() == "];").context("DAYS is not closed")?;
+    let at = (start + 1..end).rfind(|&index| day_of(&lines[index], "&day").is_some_and(|known| known < day));
+    lines.insert(at.map_or(start + 1, |index| index + 1), format!("    &day{:02}::Day{:02},", day, day));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Files a new day consists of, relative to the crate root.
+pub fn files(root: &Path, day: u8) -> Vec<PathBuf> {
+    let example = example_path(&root.join(EXAMPLES_DIR), day, "example");
+    vec![
+        root.join("src/days").join(format!("day{:02}.rs", day)),
+        root.join("src/bin").join(format!("{:02}.rs", day)),
+        example.with_extension("toml"),
+        example,
+    ]
+}
+
+/// Writes the module, binary and an empty example for `day` under `root` and registers the
+/// module, refusing to touch a day that already has any of them.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let files = files(root, day);
+    if let Some(existing) = files.iter().find(|path| path.exists()) {
+        bail!("day {:02} already exists: {}", day, existing.display());
+    }
+    let registry_path = root.join(REGISTRY);
+    let registry = fs::read_to_string(&registry_path)
+        .with_context(|| format!("cannot read {}", registry_path.display()))?;
+    let registry = register(&registry, day)?;
+
+    let contents = [render_day(day)?, render_bin(day)?, EXAMPLE_ANSWERS.to_string(), String::new()];
+    for (path, contents) in files.iter().zip(contents) {
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, contents).with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    fs::write(&registry_path, registry).with_context(|| format!("cannot write {}", registry_path.display()))?;
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_day_and_example_path() -> Result<()> {
+        let module = render_day(7)?;
+        let bin = render_bin(7)?;
+
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("fn day(&self) -> u8 {\n        7\n"));
+        assert!(module.contains("include_str!(\"../../examples/07/example.txt\")"));
+        assert!(bin.contains("use adv_code_2024::days::day07::Day07;"));
+        assert!(bin.contains("run_day(&Day07)"));
+        assert!(!module.contains("NN") && !bin.contains("NN"));
+        Ok(())
+    }
+
+    #[test]
+    fn registers_the_day_in_order() -> Result<()> {
+        let registry = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(REGISTRY))?;
+        // The registry as it was before day 21 was added
+        let without: String = registry
+            .lines()
+            .filter(|line| !line.contains("day21"))
+            .map(|line| line.replace("Solver; 25]", "Solver; 24]") + "\n")
+            .collect();
+
+        assert_eq!(registry, register(&without, 21)?);
+        assert!(register(&registry, 21).unwrap_err().to_string().contains("already registered"));
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_existing_day() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        assert!(create(root, 1).unwrap_err().to_string().contains("already exists"));
+    }
+}
@@ -0,0 +1,263 @@
+//! Shortest-path searches over any state space, given a closure listing each state's neighbours.
+//!
+//! Every search stops once it has settled all the cheapest goal states, and records the
+//! predecessors of each state on every cheapest way to it, so that one shortest path or the
+//! states of all of them can be recovered afterwards.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found.
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    /// Cost of the cheapest way to every state reached.
+    pub distances: HashMap<S, usize>,
+    /// For every state reached, the states it is entered from on its cheapest ways; empty for starts.
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// The goal states reached at the lowest cost, in the order they were settled.
+    pub goals: Vec<S>,
+    /// States whose cost is final; no further predecessors are recorded for them.
+    settled: HashSet<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Search<S> {
+        Search {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+            settled: HashSet::new(),
+        }
+    }
+
+    // Records a way to `next` costing `cost` from `from`, returning whether it is a new cheapest one.
+    // An equally cheap way only adds a predecessor while `next` is unsettled and not a start, so
+    // zero-cost steps cannot close a loop among the predecessors.
+    fn relax(&mut self, from: Option<&S>, next: S, cost: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(&best) if best < cost => false,
+            Some(&best) if best == cost => {
+                if !self.settled.contains(&next) {
+                    let previous = self.predecessors.entry(next).or_default();
+                    if !previous.is_empty() {
+                        previous.extend(from.cloned());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, from.into_iter().cloned().collect());
+                true
+            }
+        }
+    }
+
+    /// Cost of reaching the goals, or `None` if none was reached.
+    pub fn cost(&self) -> Option<usize> {
+        self.goals.first().map(|goal| self.distances[goal])
+    }
+
+    /// One shortest path from a start to the first goal, both included.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// One shortest path from a start to `end`, both included.
+    pub fn path_to(&self, end: &S) -> Option<Vec<S>> {
+        let mut state = end;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(state)?.first() {
+            path.push(previous.clone());
+            state = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from a start to `end`, both included; there can be exponentially many.
+    pub fn paths_to(&self, end: &S) -> Vec<Vec<S>> {
+        let Some(previous) = self.predecessors.get(end) else {
+            return Vec::new();
+        };
+        if previous.is_empty() {
+            return vec![vec![end.clone()]];
+        }
+
+        let mut paths = Vec::new();
+        for state in previous {
+            for mut path in self.paths_to(state) {
+                path.push(end.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// Every state lying on some shortest path from a start to one of `ends`.
+    pub fn on_shortest_paths(&self, ends: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<S> = ends.into_iter().filter(|end| self.distances.contains_key(end)).collect();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors[&state].iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.relax(None, start.clone(), 0) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = search.distances[&state];
+        if search.cost().is_some_and(|best| cost > best) {
+            break;
+        }
+        search.settled.insert(state.clone());
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            if search.relax(Some(&state), next.clone(), cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, over steps of any cost.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// A* search, guided by `heuristic`, which must never overestimate the cost left to a goal
+/// nor drop by more than the cost of a step.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if search.relax(None, start.clone(), 0) {
+            queue.push(Reverse((heuristic(&start), 0, start)));
+        }
+    }
+
+    while let Some(Reverse((estimate, cost, state))) = queue.pop() {
+        if cost > search.distances[&state] {
+            continue; // Superseded by a cheaper way to the state
+        }
+        if search.cost().is_some_and(|best| estimate > best) {
+            break;
+        }
+        if !search.settled.insert(state.clone()) {
+            continue; // Already expanded at the same cost
+        }
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if search.relax(Some(&state), next.clone(), next_cost) {
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A diamond 0 -> {1, 2} -> 3 -> 4, where the way through 2 costs as much as the way through 1.
+    fn diamond(state: &u32) -> Vec<(u32, usize)> {
+        match state {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn weighted_searches_keep_every_shortest_path() {
+        for search in [dijkstra([0], diamond, |&s| s == 4), astar([0], diamond, |&s| 4 - s as usize, |&s| s == 4)] {
+            assert_eq!(Some(4), search.cost());
+            assert_eq!(Some(vec![0, 1, 3, 4]), search.path());
+            assert_eq!(HashSet::from([0, 1, 2, 3, 4]), search.on_shortest_paths(search.goals.clone()));
+            assert_eq!(vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]], search.paths_to(&4));
+        }
+    }
+
+    #[test]
+    fn zero_cost_steps_leave_no_loops() {
+        // 0 and 1 are free to move between, as are the starts 5 and 6; both pairs lead to 2.
+        let free = |state: &u32| match state {
+            0 => vec![(1, 0), (2, 1)],
+            1 => vec![(0, 0), (2, 1)],
+            5 => vec![(6, 0), (0, 0)],
+            6 => vec![(5, 0)],
+            _ => vec![],
+        };
+
+        let search = dijkstra([0], free, |&s| s == 2);
+        assert_eq!(Some(1), search.cost());
+        assert_eq!(Some(vec![0, 2]), search.path());
+        assert_eq!(vec![vec![0, 2], vec![0, 1, 2]], search.paths_to(&2));
+
+        let search = dijkstra([5, 6], free, |&s| s == 2);
+        assert!(search.predecessors[&5].is_empty() && search.predecessors[&6].is_empty());
+        assert_eq!(Some(vec![5, 0, 2]), search.path());
+        assert_eq!(HashSet::from([5, 0, 1, 2]), search.on_shortest_paths([2]));
+    }
+
+    #[test]
+    fn bfs_counts_steps_and_stops_at_the_goal() {
+        let search = bfs([0], |&s: &i32| [s - 1, s + 1], |&s| s == 3);
+
+        assert_eq!(Some(3), search.cost());
+        assert_eq!(Some(vec![0, 1, 2, 3]), search.path());
+        assert!(!search.distances.contains_key(&5));
+        assert_eq!(None, bfs([0], |&s: &i32| [s + 1].into_iter().filter(|&n| n < 3), |&s| s == 5).path());
+    }
+}
@@ -0,0 +1,194 @@
+//! Word-search puzzles: finding words or small letter patterns in a character grid, in any
+//! orientation.
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+use anyhow::*;
+use std::collections::HashSet;
+
+/// How a pattern is laid on the grid: mirrored left to right first if `reflected`, then turned
+/// clockwise by `turns` quarter turns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub turns: u8,
+    pub reflected: bool,
+}
+
+impl Orientation {
+    /// The pattern as written.
+    pub const IDENTITY: Orientation = Orientation { turns: 0, reflected: false };
+
+    /// The identity plus, if asked, the three other quarter turns and the mirror images of all of them.
+    pub fn all(rotations: bool, reflections: bool) -> Vec<Orientation> {
+        let turns = if rotations { 0..4 } else { 0..1 };
+        let reflected: &[bool] = if reflections { &[false, true] } else { &[false] };
+        let orientations = |&reflected| turns.clone().map(move |turns| Orientation { turns, reflected });
+        reflected.iter().flat_map(orientations).collect()
+    }
+
+    /// Where an offset from the pattern's origin ends up in this orientation.
+    pub fn apply(self, offset: Point) -> Point {
+        let mut offset = if self.reflected { Point::new(offset.row, -offset.col) } else { offset };
+        for _ in 0..self.turns % 4 {
+            offset = Point::new(offset.col, -offset.row);
+        }
+        offset
+    }
+}
+
+/// A pattern of letters at offsets from its origin; cells it leaves out match anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stencil {
+    cells: Vec<(Point, char)>,
+}
+
+/// Where a stencil matched: the grid point its origin landed on, and how it was laid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub start: Point,
+    pub orientation: Orientation,
+}
+
+/// A word found in the grid, read from `start` one `direction` step at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WordMatch<'a> {
+    pub word: &'a str,
+    pub start: Point,
+    pub direction: Point,
+}
+
+impl Stencil {
+    /// Reads a stencil drawn as rows of text, with `wildcard` marking the cells that match
+    /// anything; the top-left corner is the origin.
+    pub fn parse(pattern: &str, wildcard: char) -> Result<Stencil> {
+        let mut cells = Vec::new();
+        for (row, text) in pattern.lines().enumerate() {
+            for (col, c) in text.chars().enumerate().filter(|&(_, c)| c != wildcard) {
+                cells.push((Point::new(row as isize, col as isize), c));
+            }
+        }
+        ensure!(!cells.is_empty(), "the stencil {:?} has no letters", pattern);
+        Ok(Stencil { cells })
+    }
+
+    /// The letters of `word` one `step` apart, starting at the origin.
+    pub fn word(word: &str, step: Point) -> Stencil {
+        Stencil { cells: word.chars().enumerate().map(|(i, c)| (step * i as isize, c)).collect() }
+    }
+
+    /// The same stencil laid in `orientation`.
+    pub fn oriented(&self, orientation: Orientation) -> Stencil {
+        Stencil { cells: self.cells.iter().map(|&(offset, c)| (orientation.apply(offset), c)).collect() }
+    }
+
+    /// Whether the stencil matches with its origin on `start`.
+    pub fn matches_at(&self, grid: &Grid<char>, start: Point) -> bool {
+        self.cells.iter().all(|&(offset, c)| grid.get(start + offset) == Some(&c))
+    }
+
+    /// Every place the stencil matches in one of `orientations`, row by row. Orientations that lay
+    /// the stencil out identically up to a shift (a symmetric pattern's mirror image, say) are
+    /// only tried once, under the first of them, so that no match is counted twice.
+    pub fn find(&self, grid: &Grid<char>, orientations: &[Orientation]) -> Vec<Match> {
+        let mut seen = HashSet::new();
+        let oriented: Vec<(Orientation, Stencil)> = orientations
+            .iter()
+            .map(|&orientation| (orientation, self.oriented(orientation)))
+            .filter(|(_, stencil)| seen.insert(stencil.shape()))
+            .collect();
+
+        grid.points()
+            .flat_map(|start| {
+                oriented
+                    .iter()
+                    .filter(move |(_, stencil)| stencil.matches_at(grid, start))
+                    .map(move |&(orientation, _)| Match { start, orientation })
+            })
+            .collect()
+    }
+
+    // The cells shifted so that their smallest row and column are 0, in a canonical order.
+    fn shape(&self) -> Vec<(Point, char)> {
+        let top = self.cells.iter().map(|(offset, _)| offset.row).min().unwrap_or(0);
+        let left = self.cells.iter().map(|(offset, _)| offset.col).min().unwrap_or(0);
+        let mut shape: Vec<(Point, char)> =
+            self.cells.iter().map(|&(offset, c)| (offset - Point::new(top, left), c)).collect();
+        shape.sort();
+        shape
+    }
+}
+
+/// Every occurrence of each of `words` read in a straight line in any of the eight directions,
+/// including backwards and diagonally, row by row. A palindrome is found once, read from whichever
+/// end comes first among the directions.
+pub fn find_words<'a>(grid: &Grid<char>, words: &[&'a str]) -> Vec<WordMatch<'a>> {
+    // Turning these two readings covers all eight directions
+    let directions: Vec<Point> = [Point::new(0, 1), Point::new(1, 1)]
+        .into_iter()
+        .flat_map(|step| Orientation::all(true, false).into_iter().map(move |orientation| orientation.apply(step)))
+        .collect();
+
+    let mut matches = Vec::new();
+    for &word in words {
+        // Directions that lay the word out alike, such as both ways along a palindrome, count once
+        let mut seen = HashSet::new();
+        let readings: Vec<(Point, Stencil)> = directions
+            .iter()
+            .map(|&direction| (direction, Stencil::word(word, direction)))
+            .filter(|(_, stencil)| seen.insert(stencil.shape()))
+            .collect();
+
+        for start in grid.points() {
+            for (direction, stencil) in &readings {
+                if stencil.matches_at(grid, start) {
+                    matches.push(WordMatch { word, start, direction: *direction });
+                }
+            }
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "XMAS\nMM..\nA.A.\nS..S";
+
+    #[test]
+    fn finds_words_in_every_direction() -> Result<()> {
+        let grid = Grid::parse(GRID.as_bytes())?;
+        let mut found: Vec<(Point, Point)> =
+            find_words(&grid, &["XMAS"]).iter().map(|found| (found.start, found.direction)).collect();
+        found.sort();
+
+        let expected = [((0, 0), (0, 1)), ((0, 0), (1, 0)), ((0, 0), (1, 1))];
+        let expected: Vec<(Point, Point)> =
+            expected.iter().map(|&((r, c), (dr, dc))| (Point::new(r, c), Point::new(dr, dc))).collect();
+        assert_eq!(expected, found);
+        // Across, down and diagonally, each read one way only
+        assert_eq!(3, find_words(&grid, &["MM"]).len());
+        // A single letter reads the same in every direction
+        assert_eq!(1, find_words(&Grid::parse("AB\nCD".as_bytes())?, &["A"]).len());
+        Ok(())
+    }
+
+    #[test]
+    fn orients_stencils_without_double_counting() -> Result<()> {
+        let grid = Grid::parse("M.S\n.A.\nM.S\nS.S\n.A.\nM.M".as_bytes())?;
+        let cross = Stencil::parse("M.S\n.A.\nM.S", '.')?;
+
+        let fixed = cross.find(&grid, &[Orientation::IDENTITY]);
+        assert_eq!(vec![Match { start: Point::new(0, 0), orientation: Orientation::IDENTITY }], fixed);
+
+        // The cross reflected left to right is the cross turned twice
+        let turned = cross.find(&grid, &Orientation::all(true, true));
+        assert_eq!(2, turned.len());
+        assert_eq!(Point::new(5, 0), turned[1].start);
+        assert_eq!(Orientation { turns: 3, reflected: false }, turned[1].orientation);
+
+        assert_eq!(8, Orientation::all(true, true).len());
+        assert!(Stencil::parse("..\n.", '.').is_err());
+        Ok(())
+    }
+}
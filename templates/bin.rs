@@ -0,0 +1,7 @@
+use anyhow::*;
+use adv_code_2024::*;
+use adv_code_2024::days::dayNN::DayNN;
+
+fn main() -> Result<()> {
+    run_day(&DayNN)
+}
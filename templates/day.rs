@@ -0,0 +1,56 @@
+use crate::{Answer, Solution};
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    /// TODO: Pick the parsed input both parts work on.
+    type Input = Vec<String>;
+
+    fn day(&self) -> u8 {
+        NN
+    }
+
+    fn title(&self) -> &'static str {
+        "TODO: Fill the title"
+    }
+
+    // TODO: Drop this once part 2 is solved
+    fn parts(&self) -> u8 {
+        1
+    }
+
+    fn parse<R: BufRead>(&self, reader: R) -> Result<Self::Input> {
+        Ok(reader.lines().collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        // TODO: Solve Part 1 of the puzzle
+        Ok(input.len().into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        bail!("part 2 is not solved yet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = include_str!("../../examples/NN/example.txt"); // TODO: Add the test input there
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        // TODO: Set the expected answer for the test input
+        assert_eq!(0, DayNN.part1(&DayNN.parse(TEST.as_bytes())?)?);
+        Ok(())
+    }
+
+    // #[test]
+    // fn part2_example() -> Result<()> {
+    //     assert_eq!(0, DayNN.part2(&DayNN.parse(TEST.as_bytes())?)?);
+    //     Ok(())
+    // }
+}
@@ -0,0 +1,14 @@
+//! Every fixture in `examples/` as a test; the test functions are generated by `build.rs`.
+
+use adv_code_2024::examples::{self, EXAMPLES_DIR};
+use std::path::Path;
+
+fn check(day: u8, name: &str) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+    let example = examples::load(&dir, day, name).unwrap();
+    if let Err(err) = example.check() {
+        panic!("{:#}", err);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));